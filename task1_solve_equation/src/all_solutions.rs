@@ -0,0 +1,141 @@
+//! Search of all solutions on interval.
+
+use crate::{
+    bracketing_methods::BracketingMethod,
+    root_finder::RootFinder,
+    solution::{Solution, Termination},
+};
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AllSolutions {
+    /// Found solutions, sorted by `root`.
+    pub solutions: Vec<Solution>,
+    /// Total `f` evaluations: scanning + refining.
+    pub f_evals: u64,
+}
+
+impl AllSolutions {
+    pub fn roots(&self) -> Vec<f64> {
+        self.solutions.iter().map(|s| s.root).collect()
+    }
+}
+
+
+impl RootFinder {
+    /// Finds all solutions on `[l, r]`.
+    ///
+    /// Interval is split into `subintervals` parts, then:
+    /// - every sign change of `f` is refined by bracketing `method`,
+    /// - every local min of `|f|` without sign change (near-tangent root, e.g. `x²`)
+    ///   is refined by golden section search of min of `|f|`, and accepted if `|f| < tolerance`.
+    ///
+    /// Sign changes caused by poles (e.g. `tan(x)` at `π/2`) are rejected,
+    /// because `|f|` at found "root" is bigger than at bracket ends.
+    pub fn find_all_solutions(&self, f: impl Fn(f64) -> f64, l: f64, r: f64, subintervals: u32, method: BracketingMethod) -> AllSolutions {
+        assert!(subintervals > 0);
+        let step = (r - l) / subintervals as f64;
+        let xs: Vec<f64> = (0..=subintervals).map(|i| l + step * i as f64).collect();
+        let ys: Vec<f64> = xs.iter().map(|&x| f(x)).collect();
+        let mut f_evals = xs.len() as u64;
+        let mut solutions = Vec::<Solution>::new();
+        for i in 0..xs.len() {
+            if ys[i] == 0. {
+                solutions.push(Solution::new(&f, xs[i], 0, 0, Termination::Converged));
+                continue;
+            }
+            if i + 1 < xs.len() && ys[i] * ys[i+1] < 0. {
+                let solution = self.find_solution_by(method, &f, xs[i], xs[i+1]);
+                f_evals += solution.f_evals;
+                if solution.is_converged() && solution.residual <= ys[i].abs().min(ys[i+1].abs()) {
+                    solutions.push(solution);
+                }
+                continue;
+            }
+            let is_local_min_of_abs = 0 < i && i + 1 < xs.len()
+                && ys[i-1] * ys[i] > 0. && ys[i] * ys[i+1] > 0.
+                && ys[i].abs() < ys[i-1].abs() && ys[i].abs() < ys[i+1].abs();
+            if is_local_min_of_abs {
+                let solution = self.find_min_of_abs_by_golden_section(&f, xs[i-1], xs[i+1]);
+                f_evals += solution.f_evals;
+                if solution.residual < self.tolerance {
+                    solutions.push(solution);
+                }
+            }
+        }
+        solutions.sort_by(|a, b| a.root.total_cmp(&b.root));
+        solutions.dedup_by(|a, b| (a.root - b.root).abs() < self.tolerance);
+        AllSolutions { solutions, f_evals }
+    }
+
+    /// Finds min of `|f|` on `[l, r]` by golden section search, until `r - l < tolerance`.
+    fn find_min_of_abs_by_golden_section(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        let inv_phi: f64 = (5_f64.sqrt() - 1.) / 2.;
+        let mut a = l;
+        let mut b = r;
+        let mut c = b - inv_phi * (b - a);
+        let mut d = a + inv_phi * (b - a);
+        let mut f_c = f(c).abs();
+        let mut f_d = f(d).abs();
+        let mut f_evals = 2;
+        for iter in 1..=self.max_iters {
+            if (b - a).abs() < self.tolerance {
+                return Solution::new(&f, (a + b) / 2., iter, f_evals, Termination::Converged);
+            }
+            if f_c < f_d {
+                (b, d, f_d) = (d, c, f_c);
+                c = b - inv_phi * (b - a);
+                f_c = f(c).abs();
+            } else {
+                (a, c, f_c) = (c, d, f_d);
+                d = a + inv_phi * (b - a);
+                f_d = f(d).abs();
+            }
+            f_evals += 1;
+        }
+        Solution::new(&f, (a + b) / 2., self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    #[test]
+    fn sin_x_minus_1_over_x() {
+        let all_solutions = RootFinder::new().find_all_solutions(|x| x.sin() - 1./x, 0.1, 50., 1000, BracketingMethod::Brent);
+        let roots = all_solutions.roots();
+        // near every `nπ` there are roots, plus pairs of roots in `(2πn, 2πn+π)`:
+        assert_eq!(16, roots.len(), "{roots:?}");
+        assert!((roots[0] - 1.1141571408719302).abs() < 1e-6);
+        assert!(all_solutions.solutions.iter().all(|s| s.residual < 1e-6));
+    }
+
+    #[test]
+    fn sin_multiples_of_pi() {
+        let roots = RootFinder::new().find_all_solutions(f64::sin, 1., 10., 100, BracketingMethod::Itp).roots();
+        assert_eq!(3, roots.len());
+        for (i, root) in roots.into_iter().enumerate() {
+            assert!((root - PI * (i + 1) as f64).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn tangent_root() {
+        let roots = RootFinder::new().find_all_solutions(|x| (x - 1.).powi(2), -3., 3., 100, BracketingMethod::Dichotomy).roots();
+        assert_eq!(1, roots.len());
+        assert!((roots[0] - 1.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn pole_is_not_root() {
+        let roots = RootFinder::new().find_all_solutions(f64::tan, 1., 2., 10, BracketingMethod::Ridders).roots();
+        assert_eq!(Vec::<f64>::new(), roots);
+    }
+}
@@ -0,0 +1,76 @@
+//! Methods, which use exact derivatives, calculated by [`Dual`] numbers.
+//!
+//! `f` must be written generically over [`Real`](crate::dual::Real) and passed as `f::<Dual>`,
+//! one evaluation of it gives `f(x)`, `f'(x)` and `f''(x)`, so it's counted as one `f_eval`.
+
+use crate::{
+    dual::{Dual, Real},
+    root_finder::RootFinder,
+    solution::{Solution, Termination},
+    trace::Monitor,
+};
+
+
+impl RootFinder {
+    /// Solves by Newton's method starting from `x`, derivative is exact.
+    pub fn find_solution_by_newton_autodiff(&self, f: impl Fn(Dual) -> Dual, x: f64) -> Solution {
+        self.find_solution_by_autodiff_step(f, x, |y| y.v / y.d1)
+    }
+
+    /// Solves by Halley's method starting from `x`: `x = x - 2 f f' / (2 f'² - f f'')`, it has cubic convergence.
+    pub fn find_solution_by_halley(&self, f: impl Fn(Dual) -> Dual, x: f64) -> Solution {
+        self.find_solution_by_autodiff_step(f, x, |y| 2. * y.v * y.d1 / (2. * y.d1 * y.d1 - y.v * y.d2))
+    }
+
+    /// `get_step(f(x))` returns `x_n - x_(n+1)`.
+    fn find_solution_by_autodiff_step(&self, f: impl Fn(Dual) -> Dual, x: f64, get_step: impl Fn(Dual) -> f64) -> Solution {
+        let f_real = |x: f64| f(Dual::cst(x)).v;
+        let mut x_n_m1 = x; // X_(n-1)
+        let mut f_evals = 0;
+        let mut monitor = Monitor::new(self);
+        for iter in 1..=self.max_iters {
+            let y = f(Dual::var(x_n_m1));
+            f_evals += 1;
+            if y.v == 0. { return monitor.finish(f_real, x_n_m1, iter, f_evals, Termination::Converged); }
+            let x_n = x_n_m1 - get_step(y);
+            if x_n.is_nan() { return monitor.finish(f_real, x_n_m1, iter, f_evals, Termination::FoundNan); }
+            let step = (x_n - x_n_m1).abs();
+            monitor.record(f_real, iter, x_n, step);
+            if step < self.tolerance { return monitor.finish(f_real, x_n, iter, f_evals, Termination::Converged); }
+            if monitor.is_diverging(x_n, step) { return monitor.finish(f_real, x_n, iter, f_evals, Termination::Diverged); }
+            x_n_m1 = x_n;
+        }
+        monitor.finish(f_real, x_n_m1, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f<T: Real>(x: T) -> T {
+        x.sin() - T::cst(1.) / x
+    }
+
+    const ROOT: f64 = 1.1141571408719302;
+
+    #[test]
+    fn newton_autodiff() {
+        let solution = RootFinder::new().with_tolerance(1e-15).find_solution_by_newton_autodiff(f, 1.);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-15);
+    }
+
+    #[test]
+    fn halley() {
+        let solution = RootFinder::new().with_tolerance(1e-15).find_solution_by_halley(f, 1.);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-15);
+        let solution = RootFinder::new().find_solution_by_halley(|x: Dual| x.powi(3) - Dual::cst(2.), 1.);
+        assert!((solution.root - 2_f64.cbrt()).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,338 @@
+//! Hybrid bracketing methods: root is always kept inside `[a, b]`, so they are guaranteed to converge.
+
+use crate::{
+    root_finder::RootFinder,
+    solution::{Solution, Termination},
+    trace::Monitor,
+};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BracketingMethod {
+    Dichotomy,
+    Brent,
+    Ridders,
+    Illinois,
+    AndersonBjorck,
+    Itp,
+}
+
+
+impl RootFinder {
+    /// Solves by given bracketing `method` on `[l, r]`.
+    pub fn find_solution_by(&self, method: BracketingMethod, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        match method {
+            BracketingMethod::Dichotomy      => self.find_solution_by_dichotomy(f, l, r),
+            BracketingMethod::Brent          => self.find_solution_by_brent(f, l, r),
+            BracketingMethod::Ridders        => self.find_solution_by_ridders(f, l, r),
+            BracketingMethod::Illinois       => self.find_solution_by_illinois(f, l, r),
+            BracketingMethod::AndersonBjorck => self.find_solution_by_anderson_bjorck(f, l, r),
+            BracketingMethod::Itp            => self.find_solution_by_itp(f, l, r),
+        }
+    }
+
+    /// Solves by Brent's method (inverse quadratic interpolation + secant + bisection) on `[l, r]`.
+    pub fn find_solution_by_brent(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        let mut a = l;
+        let mut b = r;
+        let mut f_a = f(a);
+        let mut f_b = f(b);
+        let mut f_evals = 2;
+        let mut monitor = Monitor::new(self);
+        if let Some((x, termination)) = check_bracket(a, f_a, b, f_b) {
+            return monitor.finish(&f, x, 0, f_evals, termination);
+        }
+        let mut c = a;
+        let mut f_c = f_a;
+        let mut d = b - a; // current step
+        let mut e = d;     // previous step
+        for iter in 1..=self.max_iters {
+            if f_b.signum() == f_c.signum() {
+                c = a;
+                f_c = f_a;
+                d = b - a;
+                e = d;
+            }
+            if f_c.abs() < f_b.abs() {
+                (a, b, c) = (b, c, b);
+                (f_a, f_b, f_c) = (f_b, f_c, f_b);
+            }
+            let tol = 2. * f64::EPSILON * b.abs() + 0.5 * self.tolerance;
+            let m = 0.5 * (c - b);
+            if m.abs() <= tol || f_b == 0. { return monitor.finish(&f, b, iter, f_evals, Termination::Converged); }
+            if e.abs() >= tol && f_a.abs() > f_b.abs() {
+                let s = f_b / f_a;
+                let (mut p, mut q) = if a == c {
+                    // secant:
+                    (2. * m * s, 1. - s)
+                } else {
+                    // inverse quadratic interpolation:
+                    let q = f_a / f_c;
+                    let r = f_b / f_c;
+                    (s * (2. * m * q * (q - r) - (b - a) * (r - 1.)), (q - 1.) * (r - 1.) * (s - 1.))
+                };
+                if p > 0. { q = -q } else { p = -p }
+                if 2. * p < (3. * m * q - (tol * q).abs()).min((e * q).abs()) {
+                    e = d;
+                    d = p / q;
+                } else {
+                    // interpolation failed => bisection:
+                    d = m;
+                    e = d;
+                }
+            } else {
+                d = m;
+                e = d;
+            }
+            a = b;
+            f_a = f_b;
+            b += if d.abs() > tol { d } else { tol.copysign(m) };
+            f_b = f(b);
+            f_evals += 1;
+            monitor.record(&f, iter, b, (b - a).abs());
+            if f_b.is_nan() { return monitor.finish(&f, a, iter, f_evals, Termination::FoundNan); }
+        }
+        monitor.finish(&f, b, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+
+    /// Solves by Ridders' method (exponential fit through bracket ends and midpoint) on `[l, r]`.
+    pub fn find_solution_by_ridders(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        let mut x_l = l;
+        let mut x_r = r;
+        let mut f_l = f(x_l);
+        let mut f_r = f(x_r);
+        let mut f_evals = 2;
+        let mut monitor = Monitor::new(self);
+        if let Some((x, termination)) = check_bracket(x_l, f_l, x_r, f_r) {
+            return monitor.finish(&f, x, 0, f_evals, termination);
+        }
+        let mut x_prev = f64::NAN;
+        for iter in 1..=self.max_iters {
+            let x_m = (x_l + x_r) / 2.;
+            let f_m = f(x_m);
+            f_evals += 1;
+            let s = (f_m*f_m - f_l*f_r).sqrt();
+            if s == 0. || s.is_nan() {
+                let termination = if f_m == 0. { Termination::Converged } else { Termination::FoundNan };
+                return monitor.finish(&f, x_m, iter, f_evals, termination);
+            }
+            let x = x_m + (x_m - x_l) * (f_l - f_r).signum() * f_m / s;
+            let f_x = f(x);
+            f_evals += 1;
+            monitor.record(&f, iter, x, (x - x_prev).abs());
+            if f_x.is_nan() { return monitor.finish(&f, x_m, iter, f_evals, Termination::FoundNan); }
+            if f_x == 0. || (x - x_prev).abs() < self.tolerance { return monitor.finish(&f, x, iter, f_evals, Termination::Converged); }
+            if f_m.signum() != f_x.signum() {
+                (x_l, f_l) = (x_m, f_m);
+                (x_r, f_r) = (x, f_x);
+            } else if f_l.signum() != f_x.signum() {
+                (x_r, f_r) = (x, f_x);
+            } else {
+                (x_l, f_l) = (x, f_x);
+            }
+            if (x_r - x_l).abs() < self.tolerance { return monitor.finish(&f, x, iter, f_evals, Termination::Converged); }
+            x_prev = x;
+        }
+        monitor.finish(&f, x_prev, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+
+    /// Solves by Illinois method (regula falsi, retained end's value is halved) on `[l, r]`.
+    pub fn find_solution_by_illinois(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        self.find_solution_by_modified_regula_falsi(f, l, r, |_f_b, _f_c| 0.5)
+    }
+
+    /// Solves by Anderson-Björck method (regula falsi, retained end's value is scaled by `1 - f_c/f_b`) on `[l, r]`.
+    pub fn find_solution_by_anderson_bjorck(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        self.find_solution_by_modified_regula_falsi(f, l, r, |f_b, f_c| {
+            let m = 1. - f_c / f_b;
+            if m > 0. { m } else { 0.5 }
+        })
+    }
+
+    /// `scale(f_b, f_c)` returns factor for value at retained end, when new point `c` is on the same side as `b`.
+    fn find_solution_by_modified_regula_falsi(
+        &self,
+        f: impl Fn(f64) -> f64,
+        l: f64,
+        r: f64,
+        scale: impl Fn(f64, f64) -> f64,
+    ) -> Solution {
+        let mut a = l;
+        let mut b = r;
+        let mut f_a = f(a);
+        let mut f_b = f(b);
+        let mut f_evals = 2;
+        let mut monitor = Monitor::new(self);
+        if let Some((x, termination)) = check_bracket(a, f_a, b, f_b) {
+            return monitor.finish(&f, x, 0, f_evals, termination);
+        }
+        for iter in 1..=self.max_iters {
+            let c = (a * f_b - b * f_a) / (f_b - f_a);
+            let f_c = f(c);
+            f_evals += 1;
+            monitor.record(&f, iter, c, (c - b).abs());
+            if f_c.is_nan() { return monitor.finish(&f, b, iter, f_evals, Termination::FoundNan); }
+            if f_c == 0. || (c - b).abs() < self.tolerance { return monitor.finish(&f, c, iter, f_evals, Termination::Converged); }
+            if f_c.signum() != f_b.signum() {
+                a = b;
+                f_a = f_b;
+            } else {
+                f_a *= scale(f_b, f_c);
+            }
+            b = c;
+            f_b = f_c;
+        }
+        monitor.finish(&f, b, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+
+    /// Solves by ITP method (Interpolate, Truncate, Project) on `[l, r]`.
+    ///
+    /// Needs at most `⌈log2((r-l)/tolerance)⌉ + 1` iterations, like dichotomy, but usually much less.
+    pub fn find_solution_by_itp(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        const K2: f64 = 2.;
+        const N0: i32 = 1;
+        let (mut a, mut b) = if l < r { (l, r) } else { (r, l) };
+        let mut f_a = f(a);
+        let mut f_b = f(b);
+        let mut f_evals = 2;
+        let mut monitor = Monitor::new(self);
+        if let Some((x, termination)) = check_bracket(a, f_a, b, f_b) {
+            return monitor.finish(&f, x, 0, f_evals, termination);
+        }
+        // make `f` increasing on `[a, b]`:
+        let sign = f_b.signum();
+        f_a *= sign;
+        f_b *= sign;
+        let eps = self.tolerance / 2.;
+        let k1 = 0.2 / (b - a);
+        let n_half = ((b - a) / (2. * eps)).log2().ceil() as i32;
+        let n_max = n_half + N0;
+        let mut x_prev = f64::NAN;
+        for iter in 1..=self.max_iters {
+            if b - a <= 2. * eps { return monitor.finish(&f, (a + b) / 2., iter, f_evals, Termination::Converged); }
+            let j = iter as i32 - 1;
+            let x_half = (a + b) / 2.;
+            let radius = eps * 2_f64.powi(n_max - j) - (b - a) / 2.;
+            let delta = k1 * (b - a).powf(K2);
+            // interpolate:
+            let x_f = (f_b * a - f_a * b) / (f_b - f_a);
+            // truncate:
+            let sigma = (x_half - x_f).signum();
+            let x_t = if delta <= (x_half - x_f).abs() { x_f + sigma * delta } else { x_half };
+            // project:
+            let x = if (x_t - x_half).abs() <= radius { x_t } else { x_half - sigma * radius };
+            let f_x = sign * f(x);
+            f_evals += 1;
+            monitor.record(&f, iter, x, (x - x_prev).abs());
+            x_prev = x;
+            if f_x.is_nan() { return monitor.finish(&f, x_half, iter, f_evals, Termination::FoundNan); }
+            if f_x > 0. {
+                b = x;
+                f_b = f_x;
+            } else if f_x < 0. {
+                a = x;
+                f_a = f_x;
+            } else {
+                return monitor.finish(&f, x, iter, f_evals, Termination::Converged);
+            }
+        }
+        monitor.finish(&f, (a + b) / 2., self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+}
+
+
+/// Point and termination, if iterations aren't needed: `f` is NaN or zero at end of `[l, r]`, or it isn't bracket.
+pub(crate) fn check_bracket(l: f64, f_l: f64, r: f64, f_r: f64) -> Option<(f64, Termination)> {
+    if f_l.is_nan() || f_r.is_nan() { return Some((r, Termination::FoundNan)); }
+    if f_l == 0. { return Some((l, Termination::Converged)); }
+    if f_r == 0. { return Some((r, Termination::Converged)); }
+    if f_l.signum() == f_r.signum() { return Some((r, Termination::NotBracketed)); }
+    None
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(x: f64) -> f64 {
+        x.sin() - 1./x
+    }
+
+    const ROOT: f64 = 1.1141571408719302;
+
+    fn assert_finds_root(find_solution: impl Fn(&RootFinder, fn(f64) -> f64, f64, f64) -> Solution) {
+        let root_finder = RootFinder::new().with_tolerance(1e-10);
+        let solution = find_solution(&root_finder, f, 0.1, 1.5);
+        assert!(solution.is_converged(), "{solution:?}");
+        assert!((solution.root - ROOT).abs() < 1e-9, "{solution:?}");
+        // reversed bracket and decreasing function:
+        let solution = find_solution(&root_finder, |x| 1./x - x.sin(), 1.5, 0.1);
+        assert!(solution.is_converged(), "{solution:?}");
+        assert!((solution.root - ROOT).abs() < 1e-9, "{solution:?}");
+        // not bracketed:
+        let solution = find_solution(&root_finder, f, 1.5, 2.);
+        assert_eq!(Termination::NotBracketed, solution.termination);
+    }
+
+    #[test]
+    fn dichotomy() {
+        assert_finds_root(|rf, f, l, r| rf.find_solution_by_dichotomy(f, l, r));
+    }
+
+    #[test]
+    fn brent() {
+        assert_finds_root(|rf, f, l, r| rf.find_solution_by_brent(f, l, r));
+    }
+
+    #[test]
+    fn ridders() {
+        assert_finds_root(|rf, f, l, r| rf.find_solution_by_ridders(f, l, r));
+    }
+
+    #[test]
+    fn illinois() {
+        assert_finds_root(|rf, f, l, r| rf.find_solution_by_illinois(f, l, r));
+    }
+
+    #[test]
+    fn anderson_bjorck() {
+        assert_finds_root(|rf, f, l, r| rf.find_solution_by_anderson_bjorck(f, l, r));
+    }
+
+    #[test]
+    fn itp() {
+        assert_finds_root(|rf, f, l, r| rf.find_solution_by_itp(f, l, r));
+    }
+
+    #[test]
+    fn root_at_end_of_bracket() {
+        let root_finder = RootFinder::new().with_tolerance(1e-10);
+        for method in [
+            BracketingMethod::Dichotomy,
+            BracketingMethod::Brent,
+            BracketingMethod::Ridders,
+            BracketingMethod::Illinois,
+            BracketingMethod::AndersonBjorck,
+            BracketingMethod::Itp,
+        ] {
+            for (l, r) in [(0., 1.), (1., 0.)] {
+                for (root, slope) in [(l, 1.), (l, -1.), (r, 1.), (r, -1.)] {
+                    let solution = root_finder.find_solution_by(method, |x| slope * (x - root), l, r);
+                    assert!(solution.is_converged(), "{method:?}, [{l}, {r}], {root}: {solution:?}");
+                    assert!((solution.root - root).abs() < 1e-9, "{method:?}, [{l}, {r}], {root}: {solution:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nan_at_end_of_bracket() {
+        let solution = RootFinder::new().find_solution_by_brent(|x| if x > 1. { f64::NAN } else { x }, -1., 2.);
+        assert_eq!(Termination::FoundNan, solution.termination);
+        assert_eq!(0, solution.iters);
+    }
+}
@@ -0,0 +1,205 @@
+//! Dual numbers for forward-mode automatic differentiation.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+
+/// Number, for which math functions can be used, so `f` can be written once and evaluated
+/// either at `f64` or at [`Dual`] to get derivatives.
+///
+/// Example: `fn f<T: Real>(x: T) -> T { x.sin() - T::cst(1.) / x }`.
+pub trait Real:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Constant.
+    fn cst(c: f64) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn abs(self) -> Self;
+}
+
+impl Real for f64 {
+    fn cst(c: f64) -> Self { c }
+    fn sin(self) -> Self { f64::sin(self) }
+    fn cos(self) -> Self { f64::cos(self) }
+    fn tan(self) -> Self { f64::tan(self) }
+    fn exp(self) -> Self { f64::exp(self) }
+    fn ln(self) -> Self { f64::ln(self) }
+    fn sqrt(self) -> Self { f64::sqrt(self) }
+    fn powi(self, n: i32) -> Self { f64::powi(self, n) }
+    fn abs(self) -> Self { f64::abs(self) }
+}
+
+
+/// Second-order dual number: value of `u(x)` and its exact derivatives `u'(x)` and `u''(x)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual {
+    pub v: f64,
+    pub d1: f64,
+    pub d2: f64,
+}
+
+impl Dual {
+    pub fn new(v: f64, d1: f64, d2: f64) -> Self {
+        Self { v, d1, d2 }
+    }
+
+    /// Returns `x` as variable, by which derivatives are calculated: `x' = 1`, `x'' = 0`.
+    pub fn var(x: f64) -> Self {
+        Self::new(x, 1., 0.)
+    }
+
+    /// Applies `g` by chain rule, given `g(v)`, `g'(v)` and `g''(v)`.
+    fn chain(self, g: f64, g_d1: f64, g_d2: f64) -> Self {
+        Self::new(
+            g,
+            g_d1 * self.d1,
+            g_d2 * self.d1 * self.d1 + g_d1 * self.d2,
+        )
+    }
+
+    fn recip(self) -> Self {
+        let r = 1. / self.v;
+        self.chain(r, -r*r, 2.*r*r*r)
+    }
+}
+
+impl Real for Dual {
+    fn cst(c: f64) -> Self { Self::new(c, 0., 0.) }
+
+    fn sin(self) -> Self {
+        let (s, c) = self.v.sin_cos();
+        self.chain(s, c, -s)
+    }
+
+    fn cos(self) -> Self {
+        let (s, c) = self.v.sin_cos();
+        self.chain(c, -s, -c)
+    }
+
+    fn tan(self) -> Self {
+        let t = self.v.tan();
+        self.chain(t, 1. + t*t, 2. * t * (1. + t*t))
+    }
+
+    fn exp(self) -> Self {
+        let e = self.v.exp();
+        self.chain(e, e, e)
+    }
+
+    fn ln(self) -> Self {
+        let r = 1. / self.v;
+        self.chain(self.v.ln(), r, -r*r)
+    }
+
+    fn sqrt(self) -> Self {
+        let s = self.v.sqrt();
+        self.chain(s, 0.5 / s, -0.25 / (s*s*s))
+    }
+
+    fn powi(self, n: i32) -> Self {
+        match n {
+            0 => Self::cst(1.),
+            1 => self,
+            _ => {
+                let n_ = n as f64;
+                self.chain(self.v.powi(n), n_ * self.v.powi(n-1), n_ * (n_ - 1.) * self.v.powi(n-2))
+            }
+        }
+    }
+
+    fn abs(self) -> Self {
+        if self.v < 0. { -self } else { self }
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.v + rhs.v, self.d1 + rhs.d1, self.d2 + rhs.d2)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.v - rhs.v, self.d1 - rhs.d1, self.d2 - rhs.d2)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.v * rhs.v,
+            self.d1 * rhs.v + self.v * rhs.d1,
+            self.d2 * rhs.v + 2. * self.d1 * rhs.d1 + self.v * rhs.d2,
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.recip()
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.v, -self.d1, -self.d2)
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_eq_approx(expected: f64, actual: f64) {
+        assert!((expected - actual).abs() < 1e-12, "expected: {expected}, actual: {actual}");
+    }
+
+    #[test]
+    fn sin_x_minus_1_over_x() {
+        fn f<T: Real>(x: T) -> T { x.sin() - T::cst(1.) / x }
+        let x = 1.3;
+        let y = f(Dual::var(x));
+        assert_eq_approx(f(x), y.v);
+        assert_eq_approx(x.cos() + 1./(x*x), y.d1);
+        assert_eq_approx(-x.sin() - 2./(x*x*x), y.d2);
+    }
+
+    #[test]
+    fn exp_ln_sqrt_powi() {
+        fn f<T: Real>(x: T) -> T { x.exp() * x.ln() + x.sqrt() - x.powi(3) }
+        let x = 2.1;
+        let y = f(Dual::var(x));
+        assert_eq_approx(f(x), y.v);
+        assert_eq_approx(x.exp() * (x.ln() + 1./x) + 0.5/x.sqrt() - 3.*x*x, y.d1);
+        assert_eq_approx(x.exp() * (x.ln() + 2./x - 1./(x*x)) - 0.25/x.powf(1.5) - 6.*x, y.d2);
+    }
+
+    #[test]
+    fn tan_cos() {
+        let x = 0.7;
+        let y = Dual::var(x).tan() * Dual::var(x).cos();
+        assert_eq_approx(x.sin(), y.v);
+        assert_eq_approx(x.cos(), y.d1);
+        assert_eq_approx(-x.sin(), y.d2);
+    }
+}
@@ -0,0 +1,140 @@
+//! Fixed-point iterations `x = g(x)`, where `g(x) = x - λ f(x)`, with optional acceleration.
+
+use crate::{
+    root_finder::RootFinder,
+    solution::{Solution, Termination},
+    trace::Monitor,
+};
+
+
+/// How to choose `λ` in `x = x - λ f(x)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Relaxation {
+    Fixed(f64),
+    /// `λ = 1 / f'(x_0)`, so `g'(x_0) = 0`, derivative is found by central difference (2 `f_evals`).
+    Auto,
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acceleration {
+    /// Aitken Δ²: plain iterations `x_n` are transformed into `x_n - (Δx_n)² / Δ²x_n`, which converges faster.
+    Aitken,
+    /// Steffensen: Aitken Δ² is applied to every two steps and iterations are restarted from it,
+    /// this gives quadratic convergence for 2 `f_evals` per iteration.
+    Steffensen,
+}
+
+
+impl RootFinder {
+    /// Solves by fixed-point iterations `x = x - λ f(x)` starting from `x`.
+    pub fn find_solution_by_fixed_point(
+        &self,
+        f: impl Fn(f64) -> f64,
+        x: f64,
+        relaxation: Relaxation,
+        acceleration: Option<Acceleration>,
+    ) -> Solution {
+        let mut f_evals = 0;
+        let lambda = match relaxation {
+            Relaxation::Fixed(lambda) => lambda,
+            Relaxation::Auto => {
+                const DELTA: f64 = 1e-3;
+                f_evals += 2;
+                (2.*DELTA) / (f(x+DELTA) - f(x-DELTA))
+            }
+        };
+        let g = |x: f64| x - lambda * f(x);
+        let mut monitor = Monitor::new(self);
+        let mut x_prev = x;
+        // for Aitken: X_(n-2) and X_(n-1) of plain iterations:
+        let mut x_n_m2 = f64::NAN;
+        let mut x_n_m1 = x;
+        for iter in 1..=self.max_iters {
+            let x = match acceleration {
+                None => {
+                    f_evals += 1;
+                    g(x_prev)
+                }
+                Some(Acceleration::Aitken) => {
+                    let x_n = g(x_n_m1);
+                    f_evals += 1;
+                    let x_acc = aitken(x_n_m2, x_n_m1, x_n);
+                    (x_n_m2, x_n_m1) = (x_n_m1, x_n);
+                    // at first iteration there are not enough points yet:
+                    if x_acc.is_finite() { x_acc } else { x_n }
+                }
+                Some(Acceleration::Steffensen) => {
+                    let x1 = g(x_prev);
+                    let x2 = g(x1);
+                    f_evals += 2;
+                    let x_acc = aitken(x_prev, x1, x2);
+                    if x_acc.is_finite() { x_acc } else { x2 }
+                }
+            };
+            if x.is_nan() { return monitor.finish(&f, x_prev, iter, f_evals, Termination::FoundNan); }
+            let step = (x - x_prev).abs();
+            monitor.record(&f, iter, x, step);
+            if step < self.tolerance { return monitor.finish(&f, x, iter, f_evals, Termination::Converged); }
+            if monitor.is_diverging(x, step) { return monitor.finish(&f, x, iter, f_evals, Termination::Diverged); }
+            x_prev = x;
+        }
+        monitor.finish(&f, x_prev, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+}
+
+
+/// Aitken Δ²: `x0 - (x1 - x0)² / (x2 - 2 x1 + x0)`.
+fn aitken(x0: f64, x1: f64, x2: f64) -> f64 {
+    x0 - (x1 - x0).powi(2) / (x2 - 2.*x1 + x0)
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Direct iterations `x = x - f(x)` diverge here, because `|1 - f'(x)| = 2 + sin(x) > 1`.
+    fn f(x: f64) -> f64 {
+        x.cos() - x
+    }
+
+    const ROOT: f64 = 0.7390851332151607;
+
+    #[test]
+    fn direct_iterations_diverge() {
+        let solution = RootFinder::new().find_solution_by_fixed_point(f, 1., Relaxation::Fixed(1.), None);
+        assert_eq!(Termination::Diverged, solution.termination);
+    }
+
+    #[test]
+    fn relaxation() {
+        let solution = RootFinder::new().find_solution_by_fixed_point(f, 1., Relaxation::Fixed(-0.5), None);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-6);
+        let solution = RootFinder::new().find_solution_by_fixed_point(f, 1., Relaxation::Auto, None);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-6);
+    }
+
+    #[test]
+    fn aitken_is_faster() {
+        let root_finder = RootFinder::new().with_tolerance(1e-10);
+        let plain  = root_finder.find_solution_by_fixed_point(f, 1., Relaxation::Fixed(-0.3), None);
+        let aitken = root_finder.find_solution_by_fixed_point(f, 1., Relaxation::Fixed(-0.3), Some(Acceleration::Aitken));
+        assert!(aitken.is_converged());
+        assert!((aitken.root - ROOT).abs() < 1e-9);
+        assert!(aitken.f_evals < plain.f_evals);
+    }
+
+    #[test]
+    fn steffensen() {
+        // converges even without relaxation, where direct iterations diverge:
+        let solution = RootFinder::new().with_tolerance(1e-12).find_solution_by_fixed_point(f, 1., Relaxation::Fixed(1.), Some(Acceleration::Steffensen));
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,20 @@
+//! Library for solving arbitrary equations `f(x) = 0`.
+
+pub mod all_solutions;
+pub mod autodiff_methods;
+pub mod bracketing_methods;
+pub mod dual;
+pub mod fixed_point_methods;
+pub mod root_finder;
+pub mod solution;
+pub mod trace;
+
+pub use crate::{
+    all_solutions::AllSolutions,
+    bracketing_methods::BracketingMethod,
+    dual::{Dual, Real},
+    fixed_point_methods::{Acceleration, Relaxation},
+    root_finder::RootFinder,
+    solution::{Solution, SolverError, Termination},
+    trace::{Trace, TraceEntry},
+};
@@ -1,93 +1,119 @@
 //! Solution of arbitrary equation.
 
-use std::cmp::Ordering;
+use std::env;
 
+use solve_equation::{Acceleration, BracketingMethod, Dual, Real, Relaxation, RootFinder};
 
-fn f(x: f64) -> f64 {
-    x.sin() - 1./x
+
+fn f<T: Real>(x: T) -> T {
+    x.sin() - T::cst(1.) / x
 }
 
 
+/// Usage: `solve_equation [TRACES_DIR]`, if `TRACES_DIR` is given, iteration traces are written there as TSV.
 pub fn main() {
-    println!("solutions:");
+    let traces_dir: Option<String> = env::args().nth(1);
 
-    let (solution, f_evals) = find_solution_by_dichotomy(0.1, 1.5, &mut 0);
-    println!("by dichotomy   : x = {x}\tf_evals: {fe}", x=solution, fe=f_evals);
-    // answer: 1.114156723022461  , f_evals = 36
+    let root_finder = RootFinder::new();
 
-    let (solution, f_evals) = find_solution_by_chords(0.1, 1.5);
-    println!("by chords      : x = {x}\tf_evals: {fe}", x=solution, fe=f_evals);
-    // answer: 1.1141571408717854 , f_evals = 24
+    println!("solutions:");
 
-    let (solution, f_evals) = find_solution_by_newton(1.);
-    println!("by newton      : x = {x}\tf_evals: {fe}", x=solution, fe=f_evals);
-    // answer: 1.114157140871924  , f_evals = 12
+    let solution = root_finder.find_solution_by_dichotomy(f, 0.1, 1.5);
+    println!("by dichotomy   : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.114156723022461  , f_evals = 21
 
-    let (solution, f_evals) = find_solution_by_direct_iterations(1.);
-    println!("by direct iters: x = {x}\tf_evals: {fe}", x=solution, fe=f_evals);
-    // answer: 1.1141570186671306 , f_evals = 10
-}
+    let solution = root_finder.find_solution_by_chords(f, 0.1, 1.5);
+    println!("by chords      : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141571408717854 , f_evals = 9
 
+    let solution = root_finder.find_solution_by_newton(f, 1.);
+    println!("by newton      : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.114157140871924  , f_evals = 12
 
-const TOLERANCE: f64 = 1e-6;
+    let solution = root_finder.find_solution_by_newton_autodiff(f::<Dual>, 1.);
+    println!("by newton (ad) : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.11415714087193   , f_evals = 4
 
+    let solution = root_finder.find_solution_by_halley(f::<Dual>, 1.);
+    println!("by halley (ad) : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.11415714087193   , f_evals = 3
 
-fn find_solution_by_dichotomy(l: f64, r: f64, f_evals: &mut u64) -> (f64, u64) {
-    let m = (l + r) / 2.;
-    if f(m).abs() < TOLERANCE { return (m, *f_evals); }
-    *f_evals += 2;
-    match f(m).partial_cmp(&0.) {
-        Some(Ordering::Greater) => { find_solution_by_dichotomy(l, m, f_evals) }
-        Some(Ordering::Less)    => { find_solution_by_dichotomy(m, r, f_evals) }
-        _ => { panic!() }
-    }
-}
+    let solution = root_finder.find_solution_by_direct_iterations(f, 1.);
+    println!("by direct iters: x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141570186671306 , f_evals = 10
 
-fn find_solution_by_chords(x0: f64, x1: f64) -> (f64, u64) {
-    const MAX_ITERS: u32 = 30;
-    let mut f_evals = 0;
-    let mut x_n_m2 = x0; // X_(n-2)
-    let mut x_n_m1 = x1; // X_(n-1)
-    let mut x_n    = 0.; // X_n
-    for _ in 0..MAX_ITERS {
-        x_n = x_n_m1 - f(x_n_m1) * (x_n_m1 - x_n_m2) / (f(x_n_m1) - f(x_n_m2));
-        f_evals += 3;
-        if (x_n - x_n_m1).abs() < TOLERANCE { break }
-        x_n_m2 = x_n_m1;
-        x_n_m1 = x_n;
+    let solution = root_finder.find_solution_by_fixed_point(f, 1., Relaxation::Auto, None);
+    println!("by auto λ      : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141570433174741 , f_evals = 10
+
+    let solution = root_finder.find_solution_by_fixed_point(f, 1., Relaxation::Fixed(1.), Some(Acceleration::Aitken));
+    println!("by aitken      : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141571450501762 , f_evals = 7
+
+    let solution = root_finder.find_solution_by_fixed_point(f, 1., Relaxation::Fixed(1.), Some(Acceleration::Steffensen));
+    println!("by steffensen  : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141571408719302 , f_evals = 8
+
+    let solution = root_finder.find_solution_by_brent(f, 0.1, 1.5);
+    println!("by brent       : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141571412498463 , f_evals = 10
+
+    let solution = root_finder.find_solution_by_ridders(f, 0.1, 1.5);
+    println!("by ridders     : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141571408694342 , f_evals = 10
+
+    let solution = root_finder.find_solution_by_illinois(f, 0.1, 1.5);
+    println!("by illinois    : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.1141571408719302 , f_evals = 12
+
+    let solution = root_finder.find_solution_by_anderson_bjorck(f, 0.1, 1.5);
+    println!("by and.-björck : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.114157141251341  , f_evals = 8
+
+    let solution = root_finder.find_solution_by_itp(f, 0.1, 1.5);
+    println!("by itp         : x = {x}\tf_evals: {fe}", x=solution.root, fe=solution.f_evals);
+    // answer: 1.114156946708776  , f_evals = 9
+
+    println!();
+    println!("achievable precision (tolerance = 1e-15):");
+    let root_finder_precise = root_finder.with_tolerance(1e-15);
+    for (name, solution) in [
+        ("newton (fd)", root_finder_precise.find_solution_by_newton(f, 1.)),
+        ("newton (ad)", root_finder_precise.find_solution_by_newton_autodiff(f::<Dual>, 1.)),
+        ("halley (ad)", root_finder_precise.find_solution_by_halley(f::<Dual>, 1.)),
+    ] {
+        println!("by {name}: x = {x}\t|f(x)| = {r:e}\tf_evals: {fe}\t{t:?}", x=solution.root, r=solution.residual, fe=solution.f_evals, t=solution.termination);
     }
-    (x_n, f_evals)
-}
 
-fn find_solution_by_newton(x: f64) -> (f64, u64) {
-    const MAX_ITERS: u32 = 30;
-    let mut f_evals = 0;
-    let mut x_n_m1 = x;  // X_(n-1)
-    let mut x_n    = 0.; // X_n
-    fn d(x: f64) -> f64 {
-        const DELTA: f64 = 1e-3;
-        (f(x+DELTA) - f(x-DELTA)) / (2.*DELTA)
+    println!();
+    println!("empirical convergence orders:");
+    let root_finder_traced = root_finder.with_trace();
+    for (name, solution) in [
+        ("dichotomy"      , root_finder_traced.find_solution_by_dichotomy(f, 0.1, 1.5)),
+        ("chords"         , root_finder_traced.find_solution_by_chords(f, 0.1, 1.5)),
+        ("newton"         , root_finder_traced.find_solution_by_newton(f, 1.)),
+        ("newton_ad"      , root_finder_traced.find_solution_by_newton_autodiff(f::<Dual>, 1.)),
+        ("halley_ad"      , root_finder_traced.find_solution_by_halley(f::<Dual>, 1.)),
+        ("direct_iters"   , root_finder_traced.find_solution_by_direct_iterations(f, 1.)),
+        ("brent"          , root_finder_traced.find_solution_by_brent(f, 0.1, 1.5)),
+        ("ridders"        , root_finder_traced.find_solution_by_ridders(f, 0.1, 1.5)),
+        ("illinois"       , root_finder_traced.find_solution_by_illinois(f, 0.1, 1.5)),
+        ("anderson_bjorck", root_finder_traced.find_solution_by_anderson_bjorck(f, 0.1, 1.5)),
+        ("itp"            , root_finder_traced.find_solution_by_itp(f, 0.1, 1.5)),
+    ] {
+        let trace = solution.trace.unwrap();
+        let q = trace.convergence_order().map_or("?".to_string(), |q| format!("{q:.2}"));
+        println!("{name:15}: q = {q}\titers: {i}\t{t:?}", i=solution.iters, t=solution.termination);
+        if let Some(traces_dir) = &traces_dir {
+            let filename = format!("{traces_dir}/trace_{name}.tsv");
+            trace.write_tsv(&filename).unwrap_or_else(|e| panic!("can't write `{filename}`: {e}"));
+        }
     }
-    for _ in 0..MAX_ITERS {
-        x_n = x_n_m1 - f(x_n_m1) / d(x_n_m1);
-        f_evals += 3;
-        if (x_n - x_n_m1).abs() < TOLERANCE { break }
-        x_n_m1 = x_n;
-    }
-    (x_n, f_evals)
-}
 
-fn find_solution_by_direct_iterations(x: f64) -> (f64, u64) {
-    const MAX_ITERS: u32 = 1000;
-    let mut f_evals = 0;
-    let mut x_prev = f64::NAN;
-    let mut x = x;
-    for _ in 0..MAX_ITERS {
-        x = -f(x) + x;
-        f_evals += 1;
-        if (x - x_prev).abs() < TOLERANCE { break }
-        x_prev = x;
+    println!();
+    let all_solutions = root_finder.find_all_solutions(f, 0.1, 50., 1000, BracketingMethod::Brent);
+    println!("all solutions on [0.1, 50] by brent (f_evals: {fe}):", fe=all_solutions.f_evals);
+    for solution in all_solutions.solutions {
+        println!("x = {x}", x=solution.root);
     }
-    (x, f_evals)
 }
-
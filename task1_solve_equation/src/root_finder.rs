@@ -0,0 +1,201 @@
+//! Root finder: configurable solver for equations `f(x) = 0` for any `f`.
+
+use crate::{
+    bracketing_methods::check_bracket,
+    fixed_point_methods::Relaxation,
+    solution::{Solution, Termination},
+    trace::Monitor,
+};
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RootFinder {
+    /// For dichotomy: `|f(x)| < tolerance` or half of bracket `< tolerance`, for others: `|x_n - x_(n-1)| < tolerance`.
+    pub tolerance: f64,
+    pub max_iters: u32,
+    /// Record per-iteration [`Trace`](crate::trace::Trace) into [`Solution`].
+    pub record_trace: bool,
+}
+
+impl Default for RootFinder {
+    fn default() -> Self {
+        Self {
+            tolerance: Self::TOLERANCE_DEFAULT,
+            max_iters: Self::MAX_ITERS_DEFAULT,
+            record_trace: false,
+        }
+    }
+}
+
+impl RootFinder {
+    pub const TOLERANCE_DEFAULT: f64 = 1e-6;
+    pub const MAX_ITERS_DEFAULT: u32 = 1000;
+
+    pub fn new() -> Self { Self::default() }
+
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    pub fn with_max_iters(self, max_iters: u32) -> Self {
+        Self { max_iters, ..self }
+    }
+
+    pub fn with_trace(self) -> Self {
+        Self { record_trace: true, ..self }
+    }
+
+    /// Solves by dichotomy (bisection) on `[l, r]`, where `f(l)` and `f(r)` have different signs.
+    pub fn find_solution_by_dichotomy(&self, f: impl Fn(f64) -> f64, l: f64, r: f64) -> Solution {
+        let mut l = l;
+        let mut r = r;
+        let f_l = f(l);
+        let f_r = f(r);
+        let mut f_evals = 2;
+        let mut monitor = Monitor::new(self);
+        if let Some((x, termination)) = check_bracket(l, f_l, r, f_r) {
+            return monitor.finish(&f, x, 0, f_evals, termination);
+        }
+        let mut m = (l + r) / 2.;
+        for iter in 1..=self.max_iters {
+            m = (l + r) / 2.;
+            let f_m = f(m);
+            f_evals += 1;
+            let step = ((r - l) / 2.).abs();
+            monitor.record(&f, iter, m, step);
+            if f_m.is_nan() { return monitor.finish(&f, m, iter, f_evals, Termination::FoundNan); }
+            if f_m.abs() < self.tolerance || step < self.tolerance {
+                return monitor.finish(&f, m, iter, f_evals, Termination::Converged);
+            }
+            if f_m.signum() == f_l.signum() { l = m } else { r = m }
+        }
+        monitor.finish(&f, m, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+
+    /// Solves by chords (secant method) starting from `x0` and `x1`.
+    pub fn find_solution_by_chords(&self, f: impl Fn(f64) -> f64, x0: f64, x1: f64) -> Solution {
+        let mut x_n_m2 = x0; // X_(n-2)
+        let mut x_n_m1 = x1; // X_(n-1)
+        let mut f_n_m2 = f(x_n_m2);
+        let mut f_n_m1 = f(x_n_m1);
+        let mut f_evals = 2;
+        let mut monitor = Monitor::new(self);
+        for iter in 1..=self.max_iters {
+            let x_n = x_n_m1 - f_n_m1 * (x_n_m1 - x_n_m2) / (f_n_m1 - f_n_m2);
+            if x_n.is_nan() { return monitor.finish(&f, x_n_m1, iter, f_evals, Termination::FoundNan); }
+            let step = (x_n - x_n_m1).abs();
+            monitor.record(&f, iter, x_n, step);
+            if step < self.tolerance { return monitor.finish(&f, x_n, iter, f_evals, Termination::Converged); }
+            if monitor.is_diverging(x_n, step) { return monitor.finish(&f, x_n, iter, f_evals, Termination::Diverged); }
+            x_n_m2 = x_n_m1;
+            f_n_m2 = f_n_m1;
+            x_n_m1 = x_n;
+            f_n_m1 = f(x_n_m1);
+            f_evals += 1;
+        }
+        monitor.finish(&f, x_n_m1, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+
+    /// Solves by Newton's method starting from `x`, derivative is found by central difference.
+    pub fn find_solution_by_newton(&self, f: impl Fn(f64) -> f64, x: f64) -> Solution {
+        const DELTA: f64 = 1e-3;
+        let d = |x: f64| (f(x+DELTA) - f(x-DELTA)) / (2.*DELTA);
+        let mut x_n_m1 = x; // X_(n-1)
+        let mut f_evals = 0;
+        let mut monitor = Monitor::new(self);
+        for iter in 1..=self.max_iters {
+            let x_n = x_n_m1 - f(x_n_m1) / d(x_n_m1);
+            f_evals += 3;
+            if x_n.is_nan() { return monitor.finish(&f, x_n_m1, iter, f_evals, Termination::FoundNan); }
+            let step = (x_n - x_n_m1).abs();
+            monitor.record(&f, iter, x_n, step);
+            if step < self.tolerance { return monitor.finish(&f, x_n, iter, f_evals, Termination::Converged); }
+            if monitor.is_diverging(x_n, step) { return monitor.finish(&f, x_n, iter, f_evals, Termination::Diverged); }
+            x_n_m1 = x_n;
+        }
+        monitor.finish(&f, x_n_m1, self.max_iters, f_evals, Termination::MaxItersReached)
+    }
+
+    /// Solves by direct iterations `x = x - f(x)` starting from `x`.
+    ///
+    /// Converges only if `|1 - f'(x)| < 1` near root,
+    /// see [`find_solution_by_fixed_point`](Self::find_solution_by_fixed_point) for relaxation and acceleration.
+    pub fn find_solution_by_direct_iterations(&self, f: impl Fn(f64) -> f64, x: f64) -> Solution {
+        self.find_solution_by_fixed_point(f, x, Relaxation::Fixed(1.), None)
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn f(x: f64) -> f64 {
+        x.sin() - 1./x
+    }
+
+    const ROOT: f64 = 1.1141571408719302;
+
+    #[test]
+    fn dichotomy() {
+        let solution = RootFinder::new().find_solution_by_dichotomy(f, 0.1, 1.5);
+        assert!(solution.is_converged());
+        assert!(solution.residual < RootFinder::TOLERANCE_DEFAULT);
+        // bracket with other orientation:
+        let solution = RootFinder::new().find_solution_by_dichotomy(|x| -f(x), 0.1, 1.5);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-5);
+    }
+
+    #[test]
+    fn chords() {
+        let solution = RootFinder::new().find_solution_by_chords(f, 0.1, 1.5);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-6);
+    }
+
+    #[test]
+    fn newton() {
+        let solution = RootFinder::new().find_solution_by_newton(f, 1.);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-6);
+        let solution = RootFinder::new().find_solution_by_newton(|x| x*x - 2., 1.);
+        assert!((solution.root - 2_f64.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn direct_iterations() {
+        let solution = RootFinder::new().find_solution_by_direct_iterations(f, 1.);
+        assert!(solution.is_converged());
+        assert!((solution.root - ROOT).abs() < 1e-5);
+    }
+
+    #[test]
+    fn max_iters_reached() {
+        let solution = RootFinder::new().with_max_iters(3).find_solution_by_dichotomy(f, 0.1, 1.5);
+        assert_eq!(Termination::MaxItersReached, solution.termination);
+        assert_eq!(3, solution.iters);
+    }
+
+    #[test]
+    fn diverged() {
+        // `x = x - f(x)` is `x = 2x` here:
+        let solution = RootFinder::new().find_solution_by_direct_iterations(|x| -x, 1.);
+        assert_eq!(Termination::Diverged, solution.termination);
+        assert!(solution.into_result().is_err());
+    }
+
+    #[test]
+    fn trace() {
+        let solution = RootFinder::new().with_trace().find_solution_by_newton(f, 1.);
+        let trace = solution.trace.unwrap();
+        assert_eq!(solution.iters as usize, trace.entries.len());
+        assert_eq!(solution.root, trace.entries.last().unwrap().x);
+        // quadratic convergence:
+        assert!(trace.convergence_order().unwrap() > 1.5);
+        assert!(RootFinder::new().find_solution_by_newton(f, 1.).trace.is_none());
+    }
+}
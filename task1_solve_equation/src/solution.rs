@@ -0,0 +1,67 @@
+//! Solution type.
+
+use std::{error::Error, fmt};
+
+use crate::trace::Trace;
+
+
+/// Why solver stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    /// Required tolerance is reached.
+    Converged,
+    /// Solver did `max_iters` iterations, but tolerance isn't reached.
+    MaxItersReached,
+    /// `f` returned `NaN` (or step became `NaN`), so solver can't continue.
+    FoundNan,
+    /// `f(l)` and `f(r)` have same sign, so bracketing method can't be used.
+    NotBracketed,
+    /// Iterations run away: `x` became infinite or steps grow for several iterations in a row.
+    Diverged,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution {
+    /// Found root: `f(root) ≈ 0`.
+    pub root: f64,
+    /// `|f(root)|`.
+    pub residual: f64,
+    pub iters: u32,
+    pub f_evals: u64,
+    pub termination: Termination,
+    /// Per-iteration trace, if [`RootFinder::with_trace`](crate::RootFinder::with_trace) is used.
+    pub trace: Option<Trace>,
+}
+
+impl Solution {
+    /// Builds solution, `residual` is calculated here, so it isn't counted in `f_evals`.
+    pub(crate) fn new(f: impl Fn(f64) -> f64, root: f64, iters: u32, f_evals: u64, termination: Termination) -> Self {
+        Self { root, residual: f(root).abs(), iters, f_evals, termination, trace: None }
+    }
+
+    pub fn is_converged(&self) -> bool {
+        self.termination == Termination::Converged
+    }
+
+    /// Returns `Err` if solver didn't converge.
+    pub fn into_result(self) -> Result<Self, SolverError> {
+        if self.is_converged() { Ok(self) } else { Err(SolverError { solution: self }) }
+    }
+}
+
+
+/// Solver didn't converge, `solution` holds last iterate and reason.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolverError {
+    pub solution: Solution,
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Solution { root, residual, iters, termination, .. } = self.solution;
+        write!(f, "solver didn't converge: {termination:?} after {iters} iters, last x = {root}, |f(x)| = {residual}")
+    }
+}
+
+impl Error for SolverError {}
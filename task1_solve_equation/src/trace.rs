@@ -0,0 +1,144 @@
+//! Iteration traces and convergence diagnostics.
+
+use std::{fs, io, path::Path};
+
+use crate::{
+    root_finder::RootFinder,
+    solution::{Solution, Termination},
+};
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TraceEntry {
+    pub iter: u32,
+    /// `x_n`.
+    pub x: f64,
+    /// `f(x_n)`.
+    pub f_x: f64,
+    /// `|x_n - x_(n-1)|`.
+    pub step: f64,
+}
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Trace {
+    pub entries: Vec<TraceEntry>,
+}
+
+impl Trace {
+    /// Steps shorter than `STEP_ROUND_OFF * ε * |x_n|` are dominated by round-off, so they are skipped.
+    const STEP_ROUND_OFF: f64 = 1e4;
+    /// Max relative difference of last two estimates of `q`, when they are considered to agree.
+    const ORDER_AGREEMENT: f64 = 0.1;
+
+    /// Estimates empirical order of convergence `q` from steps `e_n` well above round-off,
+    /// assuming `e_(n+1) ≈ C e_n^q`:
+    ///
+    /// `q ≈ ln(e_(n+1) / e_n) / ln(e_n / e_(n-1))`.
+    ///
+    /// Returns `None`, if there are less than four such steps, or last two estimates disagree
+    /// (i.e. iterations haven't reached asymptotic regime, as for hybrid bracketing methods).
+    pub fn convergence_order(&self) -> Option<f64> {
+        let steps: Vec<f64> = self.entries.iter()
+            .filter(|e| e.step.is_finite() && e.step > Self::STEP_ROUND_OFF * f64::EPSILON * e.x.abs())
+            .map(|e| e.step)
+            .collect();
+        let &[e_n_m2, e_n_m1, e_n, e_n_p1] = steps.get(steps.len().checked_sub(4)?..)? else { unreachable!() };
+        let q_prev = (e_n / e_n_m1).ln() / (e_n_m1 / e_n_m2).ln();
+        let q = (e_n_p1 / e_n).ln() / (e_n / e_n_m1).ln();
+        let is_agreed = (q - q_prev).abs() <= Self::ORDER_AGREEMENT * q.abs();
+        (q.is_finite() && is_agreed).then_some(q)
+    }
+
+    /// Returns trace as TSV with header.
+    pub fn to_tsv(&self) -> String {
+        let mut tsv = String::from("iter\tx\tf_x\tstep\n");
+        for TraceEntry { iter, x, f_x, step } in &self.entries {
+            tsv += &format!("{iter}\t{x}\t{f_x}\t{step}\n");
+        }
+        tsv
+    }
+
+    pub fn write_tsv(&self, filename: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(filename, self.to_tsv())
+    }
+}
+
+
+/// Per-solve state, shared by all methods: records trace (if enabled) and detects divergence.
+pub(crate) struct Monitor {
+    trace: Option<Trace>,
+    steps_growing: u32,
+    step_prev: f64,
+}
+
+impl Monitor {
+    /// How many consecutive growing steps mean divergence.
+    const STEPS_GROWING_MAX: u32 = 5;
+
+    pub(crate) fn new(root_finder: &RootFinder) -> Self {
+        Self {
+            trace: root_finder.record_trace.then(Trace::default),
+            steps_growing: 0,
+            step_prev: f64::INFINITY,
+        }
+    }
+
+    /// Records iteration, `f(x)` for trace is evaluated here, so it isn't counted in `f_evals`.
+    pub(crate) fn record(&mut self, f: impl Fn(f64) -> f64, iter: u32, x: f64, step: f64) {
+        if let Some(trace) = &mut self.trace {
+            trace.entries.push(TraceEntry { iter, x, f_x: f(x), step });
+        }
+    }
+
+    /// For open (non bracketing) methods: diverging, if `x` isn't finite or steps grow for several iterations in a row.
+    pub(crate) fn is_diverging(&mut self, x: f64, step: f64) -> bool {
+        if x.is_infinite() || step.is_infinite() { return true; }
+        self.steps_growing = if step > self.step_prev { self.steps_growing + 1 } else { 0 };
+        self.step_prev = step;
+        self.steps_growing >= Self::STEPS_GROWING_MAX
+    }
+
+    pub(crate) fn finish(self, f: impl Fn(f64) -> f64, root: f64, iters: u32, f_evals: u64, termination: Termination) -> Solution {
+        Solution { trace: self.trace, ..Solution::new(f, root, iters, f_evals, termination) }
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace_from_steps(steps: &[f64]) -> Trace {
+        Trace {
+            entries: steps.iter().enumerate()
+                .map(|(i, &step)| TraceEntry { iter: i as u32 + 1, x: 0., f_x: 0., step })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn convergence_order() {
+        assert_eq!(None, trace_from_steps(&[1e-1, 1e-2]).convergence_order());
+        assert_eq!(None, trace_from_steps(&[1e-1, 1e-2, 1e-3]).convergence_order());
+        let q = trace_from_steps(&[1., 1e-1, 1e-2, 1e-3]).convergence_order().unwrap();
+        assert!((q - 1.).abs() < 1e-9);
+        let q = trace_from_steps(&[1e-1, 1e-2, 1e-4, 1e-8]).convergence_order().unwrap();
+        assert!((q - 2.).abs() < 1e-9);
+        // estimates disagree:
+        assert_eq!(None, trace_from_steps(&[1., 1e-1, 1e-3, 1e-4]).convergence_order());
+        // last step is round-off:
+        let mut trace = trace_from_steps(&[1., 1e-1, 1e-2, 1e-3, 1e-15]);
+        trace.entries.iter_mut().for_each(|e| e.x = 1.);
+        let q = trace.convergence_order().unwrap();
+        assert!((q - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn to_tsv() {
+        assert_eq!("iter\tx\tf_x\tstep\n1\t0\t0\t0.5\n", trace_from_steps(&[0.5]).to_tsv());
+    }
+}
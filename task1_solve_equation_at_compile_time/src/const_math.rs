@@ -0,0 +1,462 @@
+//! Math functions, which can be evaluated at compile time (`const fn`).
+//!
+//! All of them are accurate within a few ULP across the whole `f64` range.
+
+use std::f64::consts::{FRAC_2_PI, FRAC_PI_2, FRAC_PI_4};
+
+
+pub const PI: f64 = std::f64::consts::PI;
+
+
+pub const fn abs(x: f64) -> f64 {
+    f64::from_bits(x.to_bits() & !SIGN_MASK)
+}
+
+/// Returns `x` with sign of `s`.
+pub const fn copysign(x: f64, s: f64) -> f64 {
+    f64::from_bits((x.to_bits() & !SIGN_MASK) | (s.to_bits() & SIGN_MASK))
+}
+
+pub const fn powi(x: f64, n: i32) -> f64 {
+    let mut r = 1.;
+    let mut b = if n < 0 { 1. / x } else { x };
+    let mut n = n.unsigned_abs();
+    while n > 0 {
+        if n & 1 == 1 { r *= b; }
+        b *= b;
+        n >>= 1;
+    }
+    r
+}
+
+pub const fn fact(n: u8) -> u64 {
+    let mut r = 1;
+    let mut i = 2;
+    while i <= n {
+        r *= i as u64;
+        i += 1;
+    }
+    r
+}
+
+/// Rounds to nearest integer, ties away from zero (only for `|x| < 2^63`).
+const fn round_to_i64(x: f64) -> i64 {
+    (x + copysign(0.5, x)) as i64
+}
+
+/// Returns `2^n`, for `n` in `-1022..=1023`.
+const fn pow2(n: i64) -> f64 {
+    f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+
+
+const SIGN_MASK: u64 = 1 << 63;
+const MANTISSA_MASK: u64 = (1 << 52) - 1;
+
+
+
+pub const fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0. { return f64::NAN; }
+    if x == 0. || x.is_infinite() { return x; }
+    // scale subnormals up, so bit trick below gives good initial guess:
+    let (x, scale) = if x < f64::MIN_POSITIVE { (x * pow2(108), pow2(-54)) } else { (x, 1.) };
+    // halve exponent:
+    let mut y = f64::from_bits((x.to_bits() >> 1) + (1023_u64 << 51));
+    // Newton's method: quadratic convergence, initial relative error < 6%:
+    let mut i = 0;
+    while i < 6 {
+        y = 0.5 * (y + x / y);
+        i += 1;
+    }
+    y * scale
+}
+
+
+
+const LN2_HI: f64 = 0.6931471803691238;    // 32 significant bits, so `k * LN2_HI` is exact
+const LN2_LO: f64 = 1.9082149292705877e-10; // `ln(2) - LN2_HI`
+
+pub const fn exp(x: f64) -> f64 {
+    if x.is_nan() { return x; }
+    if x > 709.782712893384 { return f64::INFINITY; }
+    if x < -745.1332191019412 { return 0.; }
+    // `x = k ln(2) + r`, `|r| <= ln(2)/2`:
+    let k = round_to_i64(x / std::f64::consts::LN_2);
+    let r = (x - k as f64 * LN2_HI) - k as f64 * LN2_LO;
+    // Taylor series of `exp(r)` by Horner's scheme, next term is `< 1e-18`:
+    let mut e = 1.;
+    let mut n = 17;
+    while n > 0 {
+        e = 1. + e * r / n as f64;
+        n -= 1;
+    }
+    // multiply by `2^k`, in two steps if `2^k` isn't normal:
+    if k > 1023 {
+        e * pow2(1023) * pow2(k - 1023)
+    } else if k < -1022 {
+        e * pow2(k + 1000) * pow2(-1000)
+    } else {
+        e * pow2(k)
+    }
+}
+
+pub const fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0. { return f64::NAN; }
+    if x == 0. { return f64::NEG_INFINITY; }
+    if x.is_infinite() { return x; }
+    let (x, mut e) = if x < f64::MIN_POSITIVE { (x * pow2(54), -54) } else { (x, 0) };
+    // `x = m 2^e`, `m` in `[sqrt(2)/2, sqrt(2))`:
+    let bits = x.to_bits();
+    e += ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mut m = f64::from_bits((bits & MANTISSA_MASK) | (1023_u64 << 52));
+    if m > std::f64::consts::SQRT_2 {
+        m /= 2.;
+        e += 1;
+    }
+    // `ln(m) = 2 atanh(s)`, where `s = (m-1)/(m+1)`, `|s| < 0.172`:
+    let s = (m - 1.) / (m + 1.);
+    let s2 = s * s;
+    let mut t = 0.;
+    let mut n = 23;
+    while n > 1 {
+        t = s2 * (1. / n as f64 + t);
+        n -= 2;
+    }
+    let ln_m = 2. * s + 2. * s * t;
+    e as f64 * LN2_HI + (e as f64 * LN2_LO + ln_m)
+}
+
+
+
+// `π/2 = PIO2_1 + PIO2_2 + PIO2_3`, first two have 33 significant bits, so `k * PIO2_i` is exact for `k < 2^20`.
+const PIO2_1: f64 = 1.5707963267341256;
+const PIO2_2: f64 = 6.077100506303966e-11;
+const PIO2_3: f64 = 2.0222662487959506e-21;
+
+/// Bits of `2/π`: `0.A2F9836E...`, enough for Payne-Hanek reduction of any `f64`.
+const TWO_OVER_PI: [u64; 20] = [
+    0xA2F9836E4E441529,
+    0xFC2757D1F534DDC0,
+    0xDB6295993C439041,
+    0xFE5163ABDEBBC561,
+    0xB7246E3A424DD2E0,
+    0x06492EEA09D1921C,
+    0xFE1DEB1CB129A73E,
+    0xE88235F52EBB4484,
+    0xE99C7026B45F7E41,
+    0x3991D639835339F4,
+    0x9C845F8BBDF9283B,
+    0x1FF897FFDE05980F,
+    0xEF2F118B5A0A6D1F,
+    0x6D367ECF27CB09B7,
+    0x4F463F669E5FEA2D,
+    0x7527BAC7EBE5F17B,
+    0x3D0739F78A5292EA,
+    0x6BFB5FB11F8D5D08,
+    0x56033046FC7B6BAB,
+    0xF0CFBC209AF4361D,
+];
+
+/// Returns 64 bits of `2/π` starting from bit `p` (bit `1` is the first after binary point).
+const fn two_over_pi_bits(p: i64) -> u64 {
+    if p + 63 < 1 { return 0; }
+    if p < 1 { return two_over_pi_bits(1) >> (1 - p); }
+    let i = ((p - 1) / 64) as usize;
+    let offset = (p - 1) % 64;
+    if offset == 0 { return TWO_OVER_PI[i]; }
+    (TWO_OVER_PI[i] << offset) | (TWO_OVER_PI[i+1] >> (64 - offset))
+}
+
+/// Returns `(k mod 4, r)`, where `x = k π/2 + r`, `|r| <= π/4`.
+const fn rem_pio2(x: f64) -> (u8, f64) {
+    let ax = abs(x);
+    if ax <= PI / 4. { return (0, x); }
+    let (k, r) = if ax < 1048576. { // 2^20
+        // Cody-Waite:
+        let k = round_to_i64(ax * FRAC_2_PI);
+        let kf = k as f64;
+        (k as u8 & 3, ((ax - kf * PIO2_1) - kf * PIO2_2) - kf * PIO2_3)
+    } else {
+        rem_pio2_large(ax)
+    };
+    if x < 0. {
+        ((4 - k) & 3, -r)
+    } else {
+        (k, r)
+    }
+}
+
+/// Payne-Hanek reduction for big positive `x`: `x 2/π mod 4` is calculated exactly by integer multiplication.
+const fn rem_pio2_large(x: f64) -> (u8, f64) {
+    // `x = m 2^e`, `m` is 53-bit integer:
+    let bits = x.to_bits();
+    let e = ((bits >> 52) & 0x7ff) as i64 - 1075;
+    let m = ((bits & MANTISSA_MASK) | (1 << 52)) as u128;
+    // bits of `2/π` before `e-1` give multiples of 4, so they are skipped,
+    // so `x 2/π mod 4 = (m * w mod 2^192) / 2^190`, where `w` is next 192 bits of `2/π`:
+    let p = e - 1;
+    let w0 = two_over_pi_bits(p) as u128;
+    let w1 = two_over_pi_bits(p + 64) as u128;
+    let w2 = two_over_pi_bits(p + 128) as u128;
+    let t2 = m * w2;
+    let t1 = m * w1 + (t2 >> 64);
+    let t0 = m * w0 + (t1 >> 64);
+    let (lo2, lo1, lo0) = (t0 as u64, t1 as u64, t2 as u64);
+    let mut k = (lo2 >> 62) as u8;
+    // fraction: next 128 bits after `k`:
+    let mut frac = ((((lo2 as u128) << 64) | lo1 as u128) << 2) | (lo0 >> 62) as u128;
+    let mut sign = 1.;
+    if frac >> 127 == 1 {
+        // fraction `>= 0.5` => `r = (frac - 1) π/2`:
+        k = (k + 1) & 3;
+        frac = frac.wrapping_neg();
+        sign = -1.;
+    }
+    let r = sign * (frac as f64) * pow2(-128) * FRAC_PI_2;
+    (k, r)
+}
+
+/// Taylor series of `sin` for `|x| <= π/4`, next term is `< 1e-25`.
+const fn sin_kernel(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut t = 0.;
+    let mut n = 21;
+    while n > 1 {
+        t = -x2 / ((n * (n - 1)) as f64) * (1. + t);
+        n -= 2;
+    }
+    x + x * t
+}
+
+/// Taylor series of `cos` for `|x| <= π/4`, next term is `< 1e-24`.
+const fn cos_kernel(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut t = 0.;
+    let mut n = 20;
+    while n > 0 {
+        t = -x2 / ((n * (n - 1)) as f64) * (1. + t);
+        n -= 2;
+    }
+    1. + t
+}
+
+pub const fn sin(x: f64) -> f64 {
+    if x.is_nan() || x.is_infinite() { return f64::NAN; }
+    let (k, r) = rem_pio2(x);
+    match k {
+        0 =>  sin_kernel(r),
+        1 =>  cos_kernel(r),
+        2 => -sin_kernel(r),
+        _ => -cos_kernel(r),
+    }
+}
+
+pub const fn cos(x: f64) -> f64 {
+    if x.is_nan() || x.is_infinite() { return f64::NAN; }
+    let (k, r) = rem_pio2(x);
+    match k {
+        0 =>  cos_kernel(r),
+        1 => -sin_kernel(r),
+        2 => -cos_kernel(r),
+        _ =>  sin_kernel(r),
+    }
+}
+
+pub const fn tan(x: f64) -> f64 {
+    if x.is_nan() || x.is_infinite() { return f64::NAN; }
+    let (k, r) = rem_pio2(x);
+    if k & 1 == 0 {
+        sin_kernel(r) / cos_kernel(r)
+    } else {
+        -cos_kernel(r) / sin_kernel(r)
+    }
+}
+
+
+
+// `atan(c) = ATAN_C_HI + ATAN_C_LO`:
+const ATAN_0_5_HI: f64 = 0.4636476090008061;
+const ATAN_0_5_LO: f64 = 2.2698777452961687e-17;
+const ATAN_1_0_HI: f64 = FRAC_PI_4;
+const ATAN_1_0_LO: f64 = 3.061616997868383e-17;
+const ATAN_1_5_HI: f64 = 0.982793723247329;
+const ATAN_1_5_LO: f64 = 1.3903311031230998e-17;
+const PIO2_HI: f64 = FRAC_PI_2;
+const PIO2_LO: f64 = 6.123233995736766e-17;
+
+pub const fn atan(x: f64) -> f64 {
+    if x.is_nan() { return x; }
+    let ax = abs(x);
+    if ax > 1e17 { return copysign(PIO2_HI, x); }
+    // `atan(x) = atan(c) + atan(t)`, where `t = (x - c) / (1 + x c)`, `|t| < 0.44`:
+    let (t, hi, lo) = if ax < 7. / 16. {
+        (ax, 0., 0.)
+    } else if ax < 11. / 16. {
+        ((2. * ax - 1.) / (2. + ax), ATAN_0_5_HI, ATAN_0_5_LO)
+    } else if ax < 19. / 16. {
+        ((ax - 1.) / (ax + 1.), ATAN_1_0_HI, ATAN_1_0_LO)
+    } else if ax < 39. / 16. {
+        ((ax - 1.5) / (1. + 1.5 * ax), ATAN_1_5_HI, ATAN_1_5_LO)
+    } else {
+        // `atan(x) = π/2 - atan(1/x)`:
+        (-1. / ax, PIO2_HI, PIO2_LO)
+    };
+    // Taylor series of `atan(t)`, next term is `< 1e-18`:
+    let t2 = t * t;
+    let mut s = 0.;
+    let mut n = 45;
+    while n > 1 {
+        s = -t2 * (1. / n as f64 + s);
+        n -= 2;
+    }
+    let r = hi + (lo + (t + t * s));
+    copysign(r, x)
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Distance between `a` and `b` in units in the last place.
+    fn ulps(a: f64, b: f64) -> u64 {
+        if a == b || (a.is_nan() && b.is_nan()) { return 0; }
+        if a.is_sign_negative() != b.is_sign_negative() { return u64::MAX; }
+        a.to_bits().abs_diff(b.to_bits())
+    }
+
+    fn assert_ulps(max_ulps: u64, f: fn(f64) -> f64, f_std: fn(f64) -> f64, xs: impl IntoIterator<Item = f64>) {
+        for x in xs {
+            let (actual, expected) = (f(x), f_std(x));
+            assert!(ulps(actual, expected) <= max_ulps, "x = {x:e}: {actual:e} != {expected:e}");
+        }
+    }
+
+    /// Points on `[-limit, limit]` with both uniform and logarithmic spacing.
+    fn points(limit: f64) -> Vec<f64> {
+        let mut xs = vec![];
+        for i in -10_000..=10_000 {
+            xs.push(limit * i as f64 / 10_000.);
+        }
+        let mut x = f64::MIN_POSITIVE;
+        while x < limit {
+            xs.push(x);
+            xs.push(-x);
+            x *= 1.0123;
+        }
+        xs
+    }
+
+    /// `xs` and their neighbours within 3 ULP.
+    fn around(xs: impl IntoIterator<Item = f64>) -> Vec<f64> {
+        xs.into_iter()
+            .flat_map(|x| (-3..=3).map(move |i: i64| f64::from_bits(x.to_bits().wrapping_add_signed(i))))
+            .collect()
+    }
+
+    /// Multiples of `π/2`, where reduced argument of trigonometric functions is tiny.
+    fn multiples_of_pi_2() -> Vec<f64> {
+        [1., 2., 3., 4., 5., 10., 100., 1_000., 100_000., 1e6, 1e7, 1e15]
+            .into_iter()
+            .flat_map(|k| [k * FRAC_PI_2, -k * FRAC_PI_2])
+            .collect()
+    }
+
+    #[test]
+    fn powi_() {
+        assert_eq!(1., powi(10., 0));
+        assert_eq!(10., powi(10., 1));
+        assert_eq!(100., powi(10., 2));
+        assert_eq!(1_000., powi(10., 3));
+        assert_eq!(2048., powi(2., 11));
+        assert_eq!(0.25, powi(2., -2));
+    }
+
+    #[test]
+    fn fact_() {
+        assert_eq!(120, fact(5));
+        assert_eq!(720, fact(6));
+    }
+
+    #[test]
+    fn sqrt_() {
+        assert_ulps(1, sqrt, f64::sqrt, points(f64::MAX).into_iter().map(abs));
+        assert_eq!(0., sqrt(0.));
+        assert_eq!(f64::INFINITY, sqrt(f64::INFINITY));
+        assert!(sqrt(-1.).is_nan());
+        assert_ulps(1, sqrt, f64::sqrt, [5e-324, 1e-310]);
+    }
+
+    #[test]
+    fn exp_() {
+        assert_ulps(2, exp, f64::exp, points(709.));
+        assert_ulps(2, exp, f64::exp, [709.78, -708., -740., -745.]);
+        // `|r| = ln(2)/2`, `2^k` stops being normal, overflow and underflow:
+        let ln_2 = std::f64::consts::LN_2;
+        assert_ulps(2, exp, f64::exp, around([ln_2/2., -ln_2/2., 1.5*ln_2, -1.5*ln_2, 1023.5*ln_2, -1022.5*ln_2, 709.782712893384, -745.1332191019412]));
+        assert_eq!(f64::INFINITY, exp(710.));
+        assert_eq!(0., exp(-746.));
+        assert_eq!(1., exp(0.));
+    }
+
+    #[test]
+    fn ln_() {
+        assert_ulps(2, ln, f64::ln, points(f64::MAX).into_iter().map(abs).filter(|&x| x > 0.));
+        assert_ulps(2, ln, f64::ln, [5e-324, 1e-310, 0.99999999, 1.00000001]);
+        // mantissa is `sqrt(2)`, subnormal and normal, largest:
+        let sqrt_2 = std::f64::consts::SQRT_2;
+        assert_ulps(2, ln, f64::ln, around([sqrt_2, sqrt_2/2., sqrt_2 * 1024., sqrt_2 * 1e-300, 1., f64::MIN_POSITIVE, f64::MAX]).into_iter().filter(|&x| x > 0.));
+        assert_eq!(0., ln(1.));
+        assert_eq!(f64::NEG_INFINITY, ln(0.));
+        assert!(ln(-1.).is_nan());
+    }
+
+    #[test]
+    fn sin_() {
+        assert_eq!(0., sin(0.));
+        assert_eq!(1., sin(PI/2.));
+        assert_ulps(2, sin, f64::sin, points(1e3));
+        assert_ulps(2, sin, f64::sin, points(f64::MAX));
+        assert_ulps(2, sin, f64::sin, [1e22, 1e300, f64::MAX, 6381956970095103. * 2_f64.powi(797)]);
+        // no reduction up to `π/4`, Cody-Waite up to `2^20`, Payne-Hanek after it:
+        assert_ulps(2, sin, f64::sin, around([PI/4., -PI/4., 1048576., -1048576.]));
+        assert_ulps(2, sin, f64::sin, around(multiples_of_pi_2()));
+        assert_ulps(2, sin, f64::sin, around([1e22, 1e100, 1e300, f64::MAX, -f64::MAX]));
+    }
+
+    #[test]
+    fn cos_() {
+        assert_eq!(1., cos(0.));
+        assert_ulps(2, cos, f64::cos, points(1e3));
+        assert_ulps(2, cos, f64::cos, points(f64::MAX));
+        assert_ulps(2, cos, f64::cos, [1e22, 1e300, f64::MAX]);
+        assert_ulps(2, cos, f64::cos, around([PI/4., -PI/4., 1048576., -1048576.]));
+        assert_ulps(2, cos, f64::cos, around(multiples_of_pi_2()));
+        assert_ulps(2, cos, f64::cos, around([1e22, 1e100, 1e300, f64::MAX, -f64::MAX]));
+    }
+
+    #[test]
+    fn tan_() {
+        assert_eq!(0., tan(0.));
+        assert_ulps(4, tan, f64::tan, points(1e3));
+        assert_ulps(4, tan, f64::tan, points(f64::MAX));
+        assert_ulps(4, tan, f64::tan, around([PI/4., -PI/4., 1048576., -1048576.]));
+        assert_ulps(4, tan, f64::tan, around(multiples_of_pi_2()));
+        assert_ulps(4, tan, f64::tan, around([1e22, 1e100, 1e300, f64::MAX, -f64::MAX]));
+    }
+
+    #[test]
+    fn atan_() {
+        assert_eq!(0., atan(0.));
+        assert_ulps(2, atan, f64::atan, points(1e3));
+        assert_ulps(2, atan, f64::atan, points(f64::MAX));
+        // boundaries of intervals with different `c`, and where `atan(x) = π/2`:
+        let bounds = [7./16., 11./16., 19./16., 39./16., 1e17];
+        assert_ulps(2, atan, f64::atan, around(bounds.into_iter().flat_map(|x| [x, -x])));
+        assert_eq!(PI/2., atan(f64::INFINITY));
+    }
+}
@@ -0,0 +1,3 @@
+//! Library for solving equations at compile time.
+
+pub mod const_math;
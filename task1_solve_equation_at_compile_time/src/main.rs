@@ -1,113 +1,120 @@
 //! Solution of arbitrary equation at compile time.
 
-#![feature(
-    const_cmp,
-    const_eval_limit,
-    const_fn_floating_point_arithmetic,
-    const_trait_impl,
-)]
+use solve_equation_at_compile_time::const_math::{abs, sin};
 
-#![const_eval_limit = "1000000"]
 
-use std::cmp::Ordering;
-
-
-
-const fn f(x: f64) -> f64 {
+/// `sin(x) - 1/x = 0`.
+const fn sin_minus_inverse(x: f64) -> f64 {
     sin(x) - 1./x
 }
 
+const_solvers!(sin_minus_inverse_solvers for sin_minus_inverse);
+use sin_minus_inverse_solvers::*;
 
-pub fn main() {
-    const X: f64 = find_solution_by_dichotomy(0.1, 1.5);
-    println!("X = {X}");
-    // answer: 1.114156723022461
-}
-
-
-const fn find_solution_by_dichotomy(l: f64, r: f64) -> f64 {
-    let m = (l + r) / 2.;
-    const TOLERANCE: f64 = 1e-6;
-    if abs(f(m)) < TOLERANCE { return m; }
-    match f(m).partial_cmp(&0.) {
-        Some(Ordering::Less)    => { find_solution_by_dichotomy(m, r) }
-        Some(Ordering::Greater) => { find_solution_by_dichotomy(l, m) }
-        _ => { panic!() }
-    }
-}
-
-
-
-
-
-const PI: f64 = 3.1415_926_535_897;
 
+const X_DICHOTOMY        : f64 = find_solution_by_dichotomy(0.1, 1.5);
+const X_CHORDS           : f64 = find_solution_by_chords(0.1, 1.5);
+const X_NEWTON           : f64 = find_solution_by_newton(1.);
+const X_DIRECT_ITERATIONS: f64 = find_solution_by_direct_iterations(1.);
 
-const fn abs(x: f64) -> f64 {
-    if x > 0. { x } else { -x }
-}
-
-const fn powi(x: f64, n: i8) -> f64 {
-    let mut r = 1.;
-    let mut i = 0;
-    while i < n {
-        r *= x;
-        i += 1;
-    }
-    r
-}
+const _: () = assert!(is_solution(X_DICHOTOMY));
+const _: () = assert!(is_solution(X_CHORDS));
+const _: () = assert!(is_solution(X_NEWTON));
+const _: () = assert!(is_solution(X_DIRECT_ITERATIONS));
 
-const fn fact(n: u8) -> u64 {
-    let mut r = 1;
-    let mut i = 2;
-    while i <= n {
-        r *= i as u64;
-        i += 1;
-    }
-    r
-}
 
-const fn sin(x: f64) -> f64 {
-    let x = x % (2.*PI); // TODO: % PI/2
-    let mut r = x;
-    let mut i = 3;
-    let mut sign = -1;
-    while i < 21 {
-        r += powi(x, i) / (sign * fact(i as u8) as i64) as f64;
-        sign = -sign;
-        i += 2;
-    }
-    r
+pub fn main() {
+    println!("solutions:");
+    println!("by dichotomy   : x = {X_DICHOTOMY}");
+    // answer: 1.114156723022461
+    println!("by chords      : x = {X_CHORDS}");
+    // answer: 1.1141571408717854
+    println!("by newton      : x = {X_NEWTON}");
+    // answer: 1.114157140871924
+    println!("by direct iters: x = {X_DIRECT_ITERATIONS}");
+    // answer: 1.1141570186671303
 }
 
 
-
-
-
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn powi_() {
-        assert_eq!(1., powi(10., 0));
-        assert_eq!(10., powi(10., 1));
-        assert_eq!(100., powi(10., 2));
-        assert_eq!(1_000., powi(10., 3));
-        assert_eq!(2048., powi(2., 11))
-    }
-
-    #[test]
-    fn fact_() {
-        assert_eq!(120, fact(5));
-        assert_eq!(720, fact(6));
-    }
-
-    #[test]
-    fn sin_() {
-        assert_eq!(0., sin(0.));
-        assert_eq!(1., sin(PI/2.));
-    }
+/// Defines module `$module` with `const fn` solvers of equation `$f(x) = 0`, where `$f` is any `const fn(f64) -> f64`.
+///
+/// Calls of trait methods and function pointers aren't allowed in `const fn` on stable Rust, so solvers are generated for each equation.
+macro_rules! const_solvers {
+    ($module:ident for $f:path) => {
+        mod $module {
+            use super::*;
+
+            const fn f(x: f64) -> f64 {
+                $f(x)
+            }
+
+
+            const TOLERANCE: f64 = 1e-6;
+            const MAX_ITERS: u32 = 1000;
+
+
+            /// Returns `true` if `|f(x)| < TOLERANCE`.
+            pub const fn is_solution(x: f64) -> bool {
+                abs(f(x)) < TOLERANCE
+            }
+
+
+            pub const fn find_solution_by_dichotomy(l: f64, r: f64) -> f64 {
+                let mut l = l;
+                let mut r = r;
+                let f_l_is_negative = f(l) < 0.;
+                let mut i = 0;
+                while i < MAX_ITERS {
+                    let m = (l + r) / 2.;
+                    let f_m = f(m);
+                    if abs(f_m) < TOLERANCE { return m; }
+                    if (f_m < 0.) == f_l_is_negative { l = m } else { r = m }
+                    i += 1;
+                }
+                panic!("dichotomy: max iterations reached")
+            }
+
+            pub const fn find_solution_by_chords(x0: f64, x1: f64) -> f64 {
+                let mut x_n_m2 = x0; // X_(n-2)
+                let mut x_n_m1 = x1; // X_(n-1)
+                let mut i = 0;
+                while i < MAX_ITERS {
+                    let f_n_m1 = f(x_n_m1);
+                    let x_n = x_n_m1 - f_n_m1 * (x_n_m1 - x_n_m2) / (f_n_m1 - f(x_n_m2));
+                    if abs(x_n - x_n_m1) < TOLERANCE { return x_n; }
+                    x_n_m2 = x_n_m1;
+                    x_n_m1 = x_n;
+                    i += 1;
+                }
+                panic!("chords: max iterations reached")
+            }
+
+            pub const fn find_solution_by_newton(x: f64) -> f64 {
+                const DELTA: f64 = 1e-3;
+                let mut x_n_m1 = x; // X_(n-1)
+                let mut i = 0;
+                while i < MAX_ITERS {
+                    let d = (f(x_n_m1+DELTA) - f(x_n_m1-DELTA)) / (2.*DELTA);
+                    let x_n = x_n_m1 - f(x_n_m1) / d;
+                    if abs(x_n - x_n_m1) < TOLERANCE { return x_n; }
+                    x_n_m1 = x_n;
+                    i += 1;
+                }
+                panic!("newton: max iterations reached")
+            }
+
+            pub const fn find_solution_by_direct_iterations(x: f64) -> f64 {
+                let mut x_prev = x;
+                let mut i = 0;
+                while i < MAX_ITERS {
+                    let x = x_prev - f(x_prev);
+                    if abs(x - x_prev) < TOLERANCE { return x; }
+                    x_prev = x;
+                    i += 1;
+                }
+                panic!("direct iterations: max iterations reached")
+            }
+        }
+    };
 }
-
+use const_solvers;
@@ -0,0 +1,19 @@
+//! Solution of Task 1 equation at macro expansion time, on stable Rust.
+
+use solve_equation_macro::solve;
+
+
+const X_BRENT    : f64 = solve!("sin(x) - 1/x", 0.1..1.5);
+const X_DICHOTOMY: f64 = solve!("sin(x) - 1/x", 0.1..1.5, dichotomy);
+const X_ITP      : f64 = solve!("sin(x) - 1/x", 0.1..1.5, itp);
+
+
+fn main() {
+    println!("solutions:");
+    println!("by brent    : x = {X_BRENT}");
+    // answer: 1.1141571408719302
+    println!("by dichotomy: x = {X_DICHOTOMY}");
+    // answer: 1.1141571408719293
+    println!("by itp      : x = {X_ITP}");
+    // answer: 1.1141571408719302
+}
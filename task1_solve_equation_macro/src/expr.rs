@@ -0,0 +1,278 @@
+//! Expression of one variable `x`, parsed from string like `"sin(x) - 1/x"`.
+//!
+//! Grammar (usual precedence, `^` is right associative, `2x` means `2*x`):
+//! ```text
+//! expr  = term  (("+" | "-") term)*
+//! term  = unary (("*" | "/")? unary)*
+//! unary = "-" unary | power
+//! power = atom ("^" unary)?
+//! atom  = number | "x" | "pi" | "e" | func "(" expr ")" | "(" expr ")"
+//! ```
+
+use std::str::FromStr;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    X,
+    Const(f64),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Pow(Box<Expr>, Box<Expr>),
+    Call(Func, Box<Expr>),
+}
+
+impl Expr {
+    pub fn eval(&self, x: f64) -> f64 {
+        match self {
+            Expr::X => x,
+            Expr::Const(c) => *c,
+            Expr::Neg(a) => -a.eval(x),
+            Expr::Add(a, b) => a.eval(x) + b.eval(x),
+            Expr::Sub(a, b) => a.eval(x) - b.eval(x),
+            Expr::Mul(a, b) => a.eval(x) * b.eval(x),
+            Expr::Div(a, b) => a.eval(x) / b.eval(x),
+            Expr::Pow(a, b) => a.eval(x).powf(b.eval(x)),
+            Expr::Call(func, a) => func.eval(a.eval(x)),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Func {
+    Sin, Cos, Tan,
+    Asin, Acos, Atan,
+    Sinh, Cosh, Tanh,
+    Exp, Ln, Sqrt, Abs,
+}
+
+impl Func {
+    const ALL: [(&'static str, Func); 13] = [
+        ("sin", Func::Sin), ("cos", Func::Cos), ("tan", Func::Tan),
+        ("asin", Func::Asin), ("acos", Func::Acos), ("atan", Func::Atan),
+        ("sinh", Func::Sinh), ("cosh", Func::Cosh), ("tanh", Func::Tanh),
+        ("exp", Func::Exp), ("ln", Func::Ln), ("sqrt", Func::Sqrt), ("abs", Func::Abs),
+    ];
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|(n, _)| *n == name).map(|&(_, func)| func)
+    }
+
+    fn eval(self, x: f64) -> f64 {
+        match self {
+            Func::Sin  => x.sin(),
+            Func::Cos  => x.cos(),
+            Func::Tan  => x.tan(),
+            Func::Asin => x.asin(),
+            Func::Acos => x.acos(),
+            Func::Atan => x.atan(),
+            Func::Sinh => x.sinh(),
+            Func::Cosh => x.cosh(),
+            Func::Tanh => x.tanh(),
+            Func::Exp  => x.exp(),
+            Func::Ln   => x.ln(),
+            Func::Sqrt => x.sqrt(),
+            Func::Abs  => x.abs(),
+        }
+    }
+}
+
+
+impl FromStr for Expr {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { tokens: tokenize(s)?, pos: 0 };
+        let expr = parser.expr()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(format!("unexpected `{token}` after end of expression")),
+        }
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(f64),
+    Ident(String),
+    Op(char),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Token::Num(n) => write!(f, "{n}"),
+            Token::Ident(name) => write!(f, "{name}"),
+            Token::Op(op) => write!(f, "{op}"),
+        }
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        }
+        else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') { i += 1 }
+            // exponent, but not constant `e` after number, like in `2e`:
+            if i < chars.len() && matches!(chars[i], 'e' | 'E') {
+                let j = if matches!(chars.get(i+1), Some('+' | '-')) { i + 2 } else { i + 1 };
+                if chars.get(j).is_some_and(|c| c.is_ascii_digit()) {
+                    i = j;
+                    while i < chars.len() && chars[i].is_ascii_digit() { i += 1 }
+                }
+            }
+            let number: String = chars[start..i].iter().collect();
+            tokens.push(Token::Num(number.parse().map_err(|_| format!("bad number `{number}`"))?));
+        }
+        else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') { i += 1 }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        }
+        else if "+-*/^()".contains(c) {
+            tokens.push(Token::Op(c));
+            i += 1;
+        }
+        else {
+            return Err(format!("unexpected character `{c}`"));
+        }
+    }
+    Ok(tokens)
+}
+
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat(&mut self, op: char) -> bool {
+        let is_op = self.peek() == Some(&Token::Op(op));
+        if is_op { self.pos += 1 }
+        is_op
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.term()?;
+        loop {
+            if self.eat('+') { lhs = Expr::Add(Box::new(lhs), Box::new(self.term()?)) }
+            else if self.eat('-') { lhs = Expr::Sub(Box::new(lhs), Box::new(self.term()?)) }
+            else { return Ok(lhs) }
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.unary()?;
+        loop {
+            if self.eat('*') { lhs = Expr::Mul(Box::new(lhs), Box::new(self.unary()?)) }
+            else if self.eat('/') { lhs = Expr::Div(Box::new(lhs), Box::new(self.unary()?)) }
+            // implicit multiplication, like `2x` or `2sin(x)`:
+            else if matches!(self.peek(), Some(Token::Num(_) | Token::Ident(_) | Token::Op('('))) {
+                lhs = Expr::Mul(Box::new(lhs), Box::new(self.unary()?))
+            }
+            else { return Ok(lhs) }
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, String> {
+        if self.eat('-') { Ok(Expr::Neg(Box::new(self.unary()?))) }
+        else { self.power() }
+    }
+
+    fn power(&mut self) -> Result<Expr, String> {
+        let base = self.atom()?;
+        if self.eat('^') { Ok(Expr::Pow(Box::new(base), Box::new(self.unary()?))) }
+        else { Ok(base) }
+    }
+
+    fn atom(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Num(n)) => Ok(Expr::Const(n)),
+            Some(Token::Op('(')) => {
+                let expr = self.expr()?;
+                if self.eat(')') { Ok(expr) } else { Err("expected `)`".to_string()) }
+            }
+            Some(Token::Ident(name)) => match name.as_str() {
+                "x" => Ok(Expr::X),
+                "pi" => Ok(Expr::Const(std::f64::consts::PI)),
+                "e" => Ok(Expr::Const(std::f64::consts::E)),
+                _ => {
+                    let Some(func) = Func::from_name(&name) else {
+                        return Err(format!("unknown variable or function `{name}`, only `x` is allowed as variable"));
+                    };
+                    if !self.eat('(') { return Err(format!("expected `(` after `{name}`")) }
+                    let arg = self.expr()?;
+                    if !self.eat(')') { return Err(format!("expected `)` after argument of `{name}`")) }
+                    Ok(Expr::Call(func, Box::new(arg)))
+                }
+            }
+            Some(token) => Err(format!("unexpected `{token}`")),
+            None => Err("unexpected end of expression".to_string()),
+        }
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(s: &str, x: f64) -> f64 {
+        s.parse::<Expr>().unwrap().eval(x)
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(7., eval("1 + 2*3", 0.));
+        assert_eq!(-4., eval("-x^2", 2.));
+        assert_eq!(2_f64.powi(9), eval("2^3^2", 0.));
+        assert_eq!(0.5, eval("2^-1", 0.));
+        assert_eq!(1., eval("8 / 4 / 2", 0.));
+        assert_eq!(9., eval("(1 + 2)(x + 1)", 2.));
+    }
+
+    #[test]
+    fn functions_and_constants() {
+        let x: f64 = 1.2;
+        assert_eq!(x.sin() - 1./x, eval("sin(x) - 1/x", x));
+        assert_eq!(2.*x.exp(), eval("2exp(x)", x));
+        assert_eq!(std::f64::consts::PI * std::f64::consts::E, eval("pi e", x));
+        assert_eq!(1.5e-3, eval("1.5e-3", x));
+        assert_eq!(2. * std::f64::consts::E, eval("2e", x));
+    }
+
+    #[test]
+    fn errors() {
+        assert!("sin x".parse::<Expr>().is_err());
+        assert!("(x + 1".parse::<Expr>().is_err());
+        assert!("y + 1".parse::<Expr>().is_err());
+        assert!("x +".parse::<Expr>().is_err());
+        assert!("x $ 1".parse::<Expr>().is_err());
+        assert!("x)".parse::<Expr>().is_err());
+    }
+}
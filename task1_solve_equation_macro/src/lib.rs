@@ -0,0 +1,144 @@
+//! `solve!` proc-macro: solves equation `f(x) = 0` at macro expansion time and expands into `f64` literal.
+//!
+//! Unlike [compile time solution](../../task1_solve_equation_at_compile_time/src/main.rs),
+//! it works on stable Rust, because equation is solved by [`solve_equation`] methods inside the compiler.
+//!
+//! ```
+//! use solve_equation_macro::solve;
+//!
+//! const X: f64 = solve!("sin(x) - 1/x", 0.1..1.5);
+//! assert!((X.sin() - 1./X).abs() < 1e-15);
+//!
+//! // bracketing method can be chosen:
+//! const Y: f64 = solve!("x^2 - 2", 0..2, ridders);
+//! assert!((Y - 2_f64.sqrt()).abs() < 1e-15);
+//! ```
+
+mod expr;
+
+use proc_macro::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Error, Expr as SynExpr, ExprLit, ExprRange, ExprUnary, Ident, Lit, LitStr, RangeLimits, Token, UnOp,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+};
+
+use solve_equation::{BracketingMethod, RootFinder, Termination};
+
+use crate::expr::Expr;
+
+
+const TOLERANCE: f64 = 1e-15;
+
+
+/// `solve!("expression of x", l..r)` or `solve!("expression of x", l..r, method)`,
+/// where `method` is one of `dichotomy`, `brent` (default), `ridders`, `illinois`, `anderson_bjorck`, `itp`.
+///
+/// Supported in expression: `+ - * / ^`, implicit multiplication (`2x`), constants `pi` and `e`,
+/// functions `sin cos tan asin acos atan sinh cosh tanh exp ln sqrt abs`.
+#[proc_macro]
+pub fn solve(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as SolveInput);
+    match input.solve() {
+        Ok(root) => quote! { #root }.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+
+struct SolveInput {
+    expr_lit: LitStr,
+    range: ExprRange,
+    method: Option<Ident>,
+}
+
+impl Parse for SolveInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expr_lit = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let range = input.parse()?;
+        let method = if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let method = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+            Some(method)
+        } else {
+            None
+        };
+        Ok(Self { expr_lit, range, method })
+    }
+}
+
+impl SolveInput {
+    fn solve(&self) -> syn::Result<f64> {
+        let expr: Expr = self.expr_lit.value().parse()
+            .map_err(|message| Error::new_spanned(&self.expr_lit, format!("can't parse expression: {message}")))?;
+        let f = |x: f64| expr.eval(x);
+        let (l, r) = self.bounds()?;
+        let method = self.method()?;
+        let solution = RootFinder::new().with_tolerance(TOLERANCE).find_solution_by(method, f, l, r);
+        let (f_l, f_r) = (f(l), f(r));
+        match solution.termination {
+            Termination::Converged if solution.residual > f_l.abs().max(f_r.abs()) => Err(Error::new_spanned(
+                &self.range,
+                format!("sign of `f` changes at x = {}, but it's a pole, not a root: |f(x)| = {}", solution.root, solution.residual),
+            )),
+            Termination::Converged => Ok(solution.root),
+            Termination::NotBracketed => Err(Error::new_spanned(
+                &self.range,
+                format!(
+                    "no root is bracketed on [{l}, {r}]: f({l}) = {f_l} and f({r}) = {f_r} have same sign, \
+                    choose range where `f` changes sign"
+                ),
+            )),
+            Termination::FoundNan => Err(Error::new_spanned(
+                &self.expr_lit,
+                format!("`f` is NaN on [{l}, {r}] (f({l}) = {f_l}, f({r}) = {f_r}), check domain of expression"),
+            )),
+            termination => Err(Error::new_spanned(
+                &self.range,
+                format!("solver didn't converge: {termination:?} after {} iterations, last x = {}", solution.iters, solution.root),
+            )),
+        }
+    }
+
+    fn bounds(&self) -> syn::Result<(f64, f64)> {
+        let (Some(l), Some(r), RangeLimits::HalfOpen(_)) = (&self.range.start, &self.range.end, self.range.limits) else {
+            return Err(Error::new_spanned(&self.range, "expected range `l..r`"));
+        };
+        let (l, r) = (eval_number(l)?, eval_number(r)?);
+        if l >= r {
+            return Err(Error::new_spanned(&self.range, format!("range is empty: {l} >= {r}")));
+        }
+        Ok((l, r))
+    }
+
+    fn method(&self) -> syn::Result<BracketingMethod> {
+        let Some(method) = &self.method else { return Ok(BracketingMethod::Brent) };
+        Ok(match method.to_string().as_str() {
+            "dichotomy"       => BracketingMethod::Dichotomy,
+            "brent"           => BracketingMethod::Brent,
+            "ridders"         => BracketingMethod::Ridders,
+            "illinois"        => BracketingMethod::Illinois,
+            "anderson_bjorck" => BracketingMethod::AndersonBjorck,
+            "itp"             => BracketingMethod::Itp,
+            _ => return Err(Error::new_spanned(
+                method,
+                "unknown method, expected one of: dichotomy, brent, ridders, illinois, anderson_bjorck, itp",
+            )),
+        })
+    }
+}
+
+
+/// Evaluates number literal, maybe negated, like `-1.5`.
+fn eval_number(expr: &SynExpr) -> syn::Result<f64> {
+    match expr {
+        SynExpr::Lit(ExprLit { lit: Lit::Float(lit), .. }) => lit.base10_parse(),
+        SynExpr::Lit(ExprLit { lit: Lit::Int(lit), .. }) => lit.base10_parse(),
+        SynExpr::Unary(ExprUnary { op: UnOp::Neg(_), expr, .. }) => Ok(-eval_number(expr)?),
+        SynExpr::Paren(paren) => eval_number(&paren.expr),
+        SynExpr::Group(group) => eval_number(&group.expr),
+        _ => Err(Error::new_spanned(expr.to_token_stream(), "expected number literal")),
+    }
+}
@@ -0,0 +1,24 @@
+use solve_equation_macro::solve;
+
+
+const ROOT: f64 = 1.1141571408719302;
+
+#[test]
+fn sin_minus_inverse() {
+    const X: f64 = solve!("sin(x) - 1/x", 0.1..1.5);
+    assert!((X - ROOT).abs() < 1e-15);
+    assert!((solve!("sin(x) - 1/x", 0.1..1.5, dichotomy) - ROOT).abs() < 1e-15);
+    assert!((solve!("sin(x) - 1/x", 0.1..1.5, itp,) - ROOT).abs() < 1e-15);
+}
+
+#[test]
+fn negative_root() {
+    const X: f64 = solve!("x^3 + 2", -2..0);
+    assert!((X + 2_f64.cbrt()).abs() < 1e-15);
+}
+
+#[test]
+fn compile_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
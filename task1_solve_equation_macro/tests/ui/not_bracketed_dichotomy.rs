@@ -0,0 +1,5 @@
+use solve_equation_macro::solve;
+
+fn main() {
+    let _ = solve!("sin(x) - 1/x", 0.1..0.5, dichotomy);
+}
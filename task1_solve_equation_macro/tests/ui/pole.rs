@@ -0,0 +1,5 @@
+use solve_equation_macro::solve;
+
+fn main() {
+    let _ = solve!("tan(x)", 1..2);
+}
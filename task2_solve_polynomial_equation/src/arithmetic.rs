@@ -0,0 +1,263 @@
+//! Polynomial arithmetic: `+`, `-`, `*`, long division, gcd and composition.
+//!
+//! All results have leading zero coefficients trimmed. Operations, which are exact for exact coefficients
+//! (like [`BigRational`](num::BigRational)), are generic, and gcd is for approximate complex coefficients.
+
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+use crate::{polynomial::Polynomial, scalar::Scalar};
+
+
+impl<T: Scalar> Polynomial<T> {
+    /// Long division: returns `(quotient, remainder)`, such that `self = quotient * rhs + remainder`
+    /// and `deg(remainder) < deg(rhs)`.
+    ///
+    /// Example:
+    ///
+    ///  1x² + 2x + 1  |  x + 1
+    ///  --------------+-------
+    ///  1x² + 2x
+    /// -
+    ///  1x² + 1x      <- 1*(x+1) => 1x
+    ///  --------                    |
+    ///        1x + 1                |
+    ///       -                      |
+    ///        1x + 1  <- 1*(x+1) => 1
+    ///        ------                |
+    ///             0                V
+    ///                              1x+1
+    ///
+    pub fn div_rem(&self, rhs: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>) {
+        assert!(!rhs.is_zero(), "division by zero polynomial");
+        let n = rhs.degree();
+        if self.degree() < n { return (Polynomial::from_coefs(vec![T::zero()]), self.clone()); }
+        let k_last = rhs.get_k_last();
+        let mut r = self.k.clone();
+        let mut q = vec![T::zero(); self.degree() - n + 1];
+        for i in (0..q.len()).rev() {
+            let c = r[i+n].clone() / k_last.clone();
+            for (j, k) in rhs.k.iter().enumerate() {
+                r[i+j] = r[i+j].clone() - c.clone() * k.clone();
+            }
+            q[i] = c;
+            // must be zero, but can be not exactly zero due to rounding:
+            r[i+n] = T::zero();
+        }
+        r.truncate(n.max(1));
+        (Polynomial::from_coefs(q), Polynomial::from_coefs(r))
+    }
+
+    /// Composition `self(q(x))`, by Horner's scheme.
+    pub fn compose(&self, q: &Polynomial<T>) -> Polynomial<T> {
+        let zero = Polynomial::from_coefs(vec![T::zero()]);
+        self.k.iter().rev().fold(zero, |acc, k| &(&acc * q) + &Polynomial::from_coefs(vec![k.clone()]))
+    }
+}
+
+
+impl Polynomial {
+    /// Relative tolerance for [`gcd`](Self::gcd): remainder coefficients smaller than it (relative to divisor) are zeros.
+    pub(crate) const GCD_TOLERANCE: f64 = 1e-9;
+
+    /// Max of coefficients' absolute values.
+    pub fn norm_inf(&self) -> f64 {
+        self.k.iter().map(|k| k.norm()).fold(0., f64::max)
+    }
+
+    /// Returns polynomial divided by leading coefficient, so it becomes `1`.
+    pub fn monic(&self) -> Self {
+        let k_last = self.get_k_last();
+        Polynomial::new(self.k.iter().map(|k| k / k_last).collect::<Vec<_>>())
+    }
+
+    /// Greatest common divisor by Euclid's algorithm, it's monic (or zero if both are zero).
+    pub fn gcd(&self, rhs: &Polynomial) -> Polynomial {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        while !b.is_zero() {
+            b = b.monic();
+            let r = a.div_rem(&b).1;
+            let r = r.trimmed_by(Self::GCD_TOLERANCE * a.norm_inf().max(1.));
+            (a, b) = (b, r);
+        }
+        if a.is_zero() { a } else { a.monic() }
+    }
+}
+
+
+impl<T: Scalar> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn neg(self) -> Polynomial<T> {
+        self.map(|k| -k.clone())
+    }
+}
+
+impl<T: Scalar> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn add(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        let (long, short) = if self.k.len() >= rhs.k.len() { (self, rhs) } else { (rhs, self) };
+        let mut k = long.k.clone();
+        for (k, k_short) in k.iter_mut().zip(&short.k) {
+            *k = k.clone() + k_short.clone();
+        }
+        Polynomial::from_coefs(k)
+    }
+}
+
+impl<T: Scalar> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn sub(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        self + &-rhs
+    }
+}
+
+impl<T: Scalar> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn mul(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        let mut k = vec![T::zero(); self.k.len() + rhs.k.len() - 1];
+        for (i, a) in self.k.iter().enumerate() {
+            for (j, b) in rhs.k.iter().enumerate() {
+                k[i+j] = k[i+j].clone() + a.clone() * b.clone();
+            }
+        }
+        Polynomial::from_coefs(k)
+    }
+}
+
+impl<T: Scalar> Div for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    /// Quotient of [`div_rem`](Polynomial::div_rem).
+    fn div(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<T: Scalar> Rem for &Polynomial<T> {
+    type Output = Polynomial<T>;
+    /// Remainder of [`div_rem`](Polynomial::div_rem).
+    fn rem(self, rhs: &Polynomial<T>) -> Polynomial<T> {
+        self.div_rem(rhs).1
+    }
+}
+
+/// Implements operator for owned polynomials by forwarding it to references.
+macro_rules! forward_owned_binop {
+    ($($trait:ident::$method:ident),*) => {$(
+        impl<T: Scalar> $trait for Polynomial<T> {
+            type Output = Polynomial<T>;
+            fn $method(self, rhs: Polynomial<T>) -> Polynomial<T> {
+                (&self).$method(&rhs)
+            }
+        }
+    )*};
+}
+forward_owned_binop!(Add::add, Sub::sub, Mul::mul, Div::div, Rem::rem);
+
+impl<T: Scalar> Neg for Polynomial<T> {
+    type Output = Polynomial<T>;
+    fn neg(self) -> Polynomial<T> {
+        -&self
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use num::{complex::Complex64, BigRational};
+
+    use super::*;
+
+    fn p(k: impl crate::MyInto<Vec<Complex64>>) -> Polynomial {
+        Polynomial::new(k)
+    }
+
+    #[test]
+    fn add_sub_neg() {
+        let a = p([1, 2, 3]);
+        let b = p([5, -2]);
+        assert_eq!(p([6, 0, 3]), &a + &b);
+        assert_eq!(a, &(&a + &b) - &b);
+        assert_eq!(p([-1, -2, -3]), -a.clone());
+        assert_eq!(Polynomial::zero(), &a - &a);
+        // leading coefficients cancel => degree decreases:
+        assert_eq!(1, (p([0, 1, 1]) - p([0, 0, 1])).degree());
+    }
+
+    #[test]
+    fn mul() {
+        // (x - 1)(x + 1) = x² - 1:
+        assert_eq!(p([-1, 0, 1]), p([-1, 1]) * p([1, 1]));
+        assert_eq!(Polynomial::zero(), p([1, 2, 3]) * Polynomial::zero());
+        let (a, b, c) = (p([1, 2]), p([3, 0, -1]), p([-2, 5, 1]));
+        // distributivity:
+        assert_eq!(&a * &(&b + &c), &(&a * &b) + &(&a * &c));
+    }
+
+    #[test]
+    fn div_rem() {
+        // x³ + 1 = x (x² + 1) + (1 - x):
+        assert_eq!((p([0, 1]), p([1, -1])), p([1, 0, 0, 1]).div_rem(&p([1, 0, 1])));
+        // division by constant:
+        assert_eq!((p([0.5, 1.]), Polynomial::zero()), p([1, 2]).div_rem(&p([2])));
+        // divisor of bigger degree:
+        assert_eq!((Polynomial::zero(), p([1, 2])), p([1, 2]).div_rem(&p([1, 2, 3])));
+        // a = q b + r:
+        let (a, b) = (p([3, -1, 4, 1, -5, 9]), p([2, 6, -5]));
+        let (q, r) = a.div_rem(&b);
+        assert!(r.degree() < b.degree());
+        assert!((&(&(&q * &b) + &r) - &a).norm_inf() < 1e-12);
+    }
+
+    #[test]
+    fn gcd() {
+        // (x - 1)(x - 2) and (x - 1)(x - 3):
+        assert_eq!(p([-1, 1]), p([2, -3, 1]).gcd(&p([3, -4, 1])));
+        // coprime:
+        assert_eq!(Polynomial::one(), p([1, 0, 1]).gcd(&p([-1, 1])));
+        // (x + 1)² (x - 2) and 3 (x + 1)²:
+        let g = p([-2, -3, 0, 1]).gcd(&p([3, 6, 3]));
+        assert!((&g - &p([1, 2, 1])).norm_inf() < 1e-12);
+    }
+
+    #[test]
+    fn compose() {
+        let a = p([1, -2, 3]);
+        let b = p([0.5, 4., -1.]);
+        let ab = a.compose(&b);
+        assert_eq!(4, ab.degree());
+        for x in [-1.5, 0., 0.3, 2.] {
+            assert!((ab.eval_at(x) - a.eval_at(b.eval_at(x))).norm() < 1e-12);
+        }
+        // x is identity:
+        assert_eq!(a, a.compose(&Polynomial::x()));
+    }
+
+    #[test]
+    fn exact_rational() {
+        let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+        // (x³ - 1/8) / (x - 1/2) = x² + x/2 + 1/4 exactly:
+        let a = Polynomial::from_coefs(vec![r(-1, 8), r(0, 1), r(0, 1), r(1, 1)]);
+        let b = Polynomial::from_coefs(vec![r(-1, 2), r(1, 1)]);
+        let (q, rem) = a.div_rem(&b);
+        assert_eq!(Polynomial::from_coefs(vec![r(1, 4), r(1, 2), r(1, 1)]), q);
+        assert!(rem.is_zero());
+        assert_eq!(a, &(&q * &b) + &rem);
+        // x³ + 1 = x (3x² + 1) / 3 + (1 - x/3):
+        let a = Polynomial::from_coefs(vec![r(1, 1), r(0, 1), r(0, 1), r(1, 1)]);
+        let b = Polynomial::from_coefs(vec![r(1, 1), r(0, 1), r(3, 1)]);
+        assert_eq!(
+            (Polynomial::from_coefs(vec![r(0, 1), r(1, 3)]), Polynomial::from_coefs(vec![r(1, 1), r(-1, 3)])),
+            a.div_rem(&b),
+        );
+    }
+
+    #[test]
+    fn real_f32() {
+        let a = Polynomial::from_coefs(vec![-1f32, 0., 1.]);
+        let b = Polynomial::from_coefs(vec![1f32, 1.]);
+        assert_eq!(Polynomial::from_coefs(vec![-1f32, 1.]), &a / &b);
+        assert_eq!(3., a.compose(&b).eval(&1.));
+    }
+}
@@ -0,0 +1,90 @@
+//! Bounds on roots: Cauchy and Fujiwara bounds of their magnitudes and Descartes' rule of signs.
+
+use crate::polynomial::Polynomial;
+
+
+impl Polynomial {
+    /// Cauchy bound: all roots are in `|z| <= 1 + max |a_i / a_n|`.
+    pub fn cauchy_bound(&self) -> f64 {
+        let k_last = self.get_k_last();
+        1. + self.k[..self.degree()].iter().map(|k| (k / k_last).norm()).fold(0., f64::max)
+    }
+
+    /// Fujiwara bound: all roots are in `|z| <= 2 max(|a_(n-1) / a_n|, |a_(n-2) / a_n|^(1/2), …, |a_0 / (2 a_n)|^(1/n))`.
+    ///
+    /// It's never worse than Cauchy bound by more than factor `2`, and usually much better.
+    pub fn fujiwara_bound(&self) -> f64 {
+        let n = self.degree();
+        let k_last = self.get_k_last();
+        2. * (1..=n)
+            .map(|i| {
+                let k = (self.k[n-i] / k_last).norm();
+                let k = if i == n { k / 2. } else { k };
+                k.powf(1. / i as f64)
+            })
+            .fold(0., f64::max)
+    }
+
+    /// All roots are in `|z| >= roots_lower_bound`, it's inverse of [Fujiwara bound](Self::fujiwara_bound)
+    /// of reversed polynomial (whose roots are `1/z`), or `0` if `0` is root.
+    pub fn roots_lower_bound(&self) -> f64 {
+        if self.k[0].norm() == 0. || self.degree() == 0 { return 0.; }
+        let reversed = Polynomial::new(self.k.iter().rev().copied().collect::<Vec<_>>());
+        1. / reversed.fujiwara_bound()
+    }
+
+    /// Descartes' rule of signs for polynomial with real coefficients: returns `(positive, negative)`,
+    /// where `positive` is number of sign changes in coefficients, and number of positive roots
+    /// (counted with multiplicity) is less than it by even number, same for negative roots and `p(-x)`.
+    pub fn descartes_rule_of_signs(&self) -> (usize, usize) {
+        assert!(self.is_real(), "Descartes' rule of signs is for polynomials with real coefficients");
+        let sign_changes = |coefs: Vec<f64>| -> usize {
+            let signs: Vec<bool> = coefs.into_iter().filter(|&k| k != 0.).map(|k| k > 0.).collect();
+            signs.windows(2).filter(|w| w[0] != w[1]).count()
+        };
+        let positive = sign_changes(self.k.iter().map(|k| k.re).collect());
+        let negative = sign_changes(self.k.iter().enumerate().map(|(i, k)| if i % 2 == 0 { k.re } else { -k.re }).collect());
+        (positive, negative)
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magnitude_bounds() {
+        for p in [
+            Polynomial::new([210, -247, 101, -17, 1]), // (x - 2)(x - 3)(x - 5)(x - 7)
+            Polynomial::new([7, 2, 7, 2, 1]),
+            Polynomial::new([-1e-6, 0., 1.]), // ±1e-3
+            Polynomial::new([6, -5, 1]).compose(&Polynomial::new([0, 100])), // 0.02, 0.03
+        ] {
+            let roots = p.find_all_solutions();
+            let max_norm = roots.iter().map(|z| z.norm()).fold(0., f64::max);
+            let min_norm = roots.iter().map(|z| z.norm()).fold(f64::INFINITY, f64::min);
+            assert!(max_norm <= p.cauchy_bound(), "{p}");
+            assert!(max_norm <= p.fujiwara_bound(), "{p}");
+            assert!(p.fujiwara_bound() <= 2. * p.cauchy_bound(), "{p}");
+            assert!(min_norm >= p.roots_lower_bound(), "{p}");
+        }
+        let p = Polynomial::new([210, -247, 101, -17, 1]);
+        assert_eq!(248., p.cauchy_bound());
+        assert_eq!(34., p.fujiwara_bound());
+        assert_eq!(0., Polynomial::new([0, 1, 1]).roots_lower_bound());
+    }
+
+    #[test]
+    fn descartes_rule_of_signs() {
+        // (x - 2)(x - 3)(x - 5)(x - 7): 4 positive roots, no negative:
+        assert_eq!((4, 0), Polynomial::new([210, -247, 101, -17, 1]).descartes_rule_of_signs());
+        // x³ + x² - x - 1 = (x - 1)(x + 1)²: 1 positive root, 2 negative:
+        assert_eq!((1, 2), Polynomial::new([-1, -1, 1, 1]).descartes_rule_of_signs());
+        // x⁴ + 2x³ + 7x² + 2x + 7: no positive roots, 0, 2 or 4 negative:
+        assert_eq!((0, 4), Polynomial::new([7, 2, 7, 2, 1]).descartes_rule_of_signs());
+    }
+}
@@ -0,0 +1,77 @@
+//! Exact derivative and antiderivative of polynomial.
+
+use num::complex::Complex64;
+
+use crate::{polynomial::Polynomial, scalar::Scalar};
+
+
+impl<T: Scalar> Polynomial<T> {
+    /// Exact derivative: `(Σ a_i x^i)' = Σ i a_i x^(i-1)`.
+    pub fn derivative(&self) -> Polynomial<T> {
+        Polynomial::from_coefs(
+            self.k.iter().zip(naturals()).skip(1)
+                .map(|(k, i)| k.clone() * i)
+                .collect()
+        )
+    }
+
+    /// `n`-th derivative.
+    pub fn nth_derivative(&self, n: usize) -> Polynomial<T> {
+        (0..n).fold(self.clone(), |p, _| p.derivative())
+    }
+
+    /// Exact antiderivative with zero integration constant: `∫ Σ a_i x^i dx = Σ a_i x^(i+1) / (i+1)`.
+    pub fn antiderivative(&self) -> Polynomial<T> {
+        Polynomial::from_coefs(
+            [T::zero()].into_iter()
+                .chain(self.k.iter().zip(naturals().skip(1)).map(|(k, i)| k.clone() / i))
+                .collect()
+        )
+    }
+}
+
+
+impl Polynomial {
+    /// Definite integral on `[a, b]`.
+    pub fn integral(&self, a: impl Into<Complex64>, b: impl Into<Complex64>) -> Complex64 {
+        let antiderivative = self.antiderivative();
+        antiderivative.eval_at(b) - antiderivative.eval_at(a)
+    }
+}
+
+
+/// `0, 1, 2, …` in `T`, without conversion from integers, which isn't in [`Scalar`].
+fn naturals<T: Scalar>() -> impl Iterator<Item = T> {
+    std::iter::successors(Some(T::zero()), |i| Some(i.clone() + T::one()))
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derivative() {
+        // (7 + 2x + 7x² + 2x³ + x⁴)' = 2 + 14x + 6x² + 4x³:
+        let p = Polynomial::new([7, 2, 7, 2, 1]);
+        assert_eq!(Polynomial::new([2, 14, 6, 4]), p.derivative());
+        assert_eq!(Polynomial::new([24]), p.nth_derivative(4));
+        assert_eq!(Polynomial::zero(), p.nth_derivative(5));
+        for x in [Complex64::new(0.3, -1.), Complex64::new(-2., 0.5)] {
+            assert!((p.derivative().eval_at(x) - p.eval_derivative_at(x)).norm() < 1e-12);
+            assert_eq!(p.eval_at(x), p.eval_with_derivative_at(x).0);
+        }
+    }
+
+    #[test]
+    fn antiderivative() {
+        let p = Polynomial::new([2, 14, 6, 4]);
+        assert_eq!(Polynomial::new([0, 2, 7, 2, 1]), p.antiderivative());
+        assert_eq!(p, p.antiderivative().derivative());
+        // ∫₀¹ 3x² dx = 1:
+        assert_eq!(Complex64::new(1., 0.), Polynomial::new([0, 0, 3]).integral(0., 1.));
+    }
+}
@@ -0,0 +1,297 @@
+//! Command-line interface: parsing of arguments and output of roots as text, CSV or JSON.
+
+use std::io::{self, IsTerminal, Read};
+
+use task2_solve_polynomial_equation::{Method, Polynomial, Roots, Solver};
+
+
+pub const USAGE: &str = "\
+Usage: task2_solve_polynomial_equation [OPTIONS] [POLYNOMIAL | COEFFICIENTS...]
+
+Polynomial is expression, like \"x^4 + 2x^3 + 7x^2 + 2x + 7\" or \"(1+2i)x^2 - 3\",
+or coefficients from lowest power `a0 a1 … an`, like `7 2 7 2 1` or `-3 0 (1+2i)`.
+If it isn't given, it's read from stdin.
+
+Options:
+  -m, --method <METHOD>     aberth (default), durand-kerner, companion or newton
+  -t, --tolerance <TOL>     relative tolerance of roots, default is 1e-14
+  -i, --max-iters <N>       default is 1000
+  -f, --format <FORMAT>     human (default), csv or json
+  -h, --help                print this help
+  --                        end of options, e.g. for coefficient `-i`, which is taken as option otherwise
+
+Every root is printed with its error bound, radius of inclusion disc and residual |p(z)|.
+Exit code is 1 if solver didn't converge, 2 if arguments are invalid.";
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Human,
+    Csv,
+    Json,
+}
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Args {
+    /// Polynomial or coefficients, `None` if they should be read from stdin.
+    pub input: Option<String>,
+    pub solver: Solver,
+    pub format: Format,
+    pub is_help: bool,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut result = Self { input: None, solver: Solver::default(), format: Format::Human, is_help: false };
+        let mut positional: Vec<String> = vec![];
+        let mut args = args.into_iter();
+        let mut is_options_end = false;
+        while let Some(arg) = args.next() {
+            if is_options_end {
+                positional.push(arg);
+                continue;
+            }
+            let mut value = || args.next().ok_or_else(|| format!("missing value of {arg}"));
+            match arg.as_str() {
+                "-m" | "--method" => {
+                    let method = match value()?.as_str() {
+                        "aberth" => Method::AberthEhrlich,
+                        "durand-kerner" => Method::DurandKerner,
+                        "companion" => Method::CompanionMatrix,
+                        "newton" => Method::NewtonDeflation,
+                        method => return Err(format!("unknown method: {method}")),
+                    };
+                    result.solver.method = method;
+                }
+                "-t" | "--tolerance" => {
+                    let tolerance = value()?;
+                    result.solver.tolerance = tolerance.parse().ok().filter(|&t: &f64| t > 0.)
+                        .ok_or_else(|| format!("invalid tolerance: {tolerance}"))?;
+                }
+                "-i" | "--max-iters" => {
+                    let max_iters = value()?;
+                    result.solver.max_iters = max_iters.parse().map_err(|_| format!("invalid max iters: {max_iters}"))?;
+                }
+                "-f" | "--format" => {
+                    result.format = match value()?.as_str() {
+                        "human" => Format::Human,
+                        "csv" => Format::Csv,
+                        "json" => Format::Json,
+                        format => return Err(format!("unknown format: {format}")),
+                    };
+                }
+                "-h" | "--help" => result.is_help = true,
+                "--" => is_options_end = true,
+                // only exact flags are options, so `-3`, `-(1+2i)` or `-x^2 + 1` are polynomial:
+                _ => positional.push(arg),
+            }
+        }
+        if !positional.is_empty() { result.input = Some(positional.join(" ")); }
+        Ok(result)
+    }
+}
+
+
+/// Parses expression, if it contains `x`, otherwise coefficients separated by whitespace (outside of parentheses).
+pub fn parse_polynomial(input: &str) -> Result<Polynomial, String> {
+    if input.contains('x') {
+        return input.parse().map_err(|e| format!("invalid polynomial: {e}"));
+    }
+    let mut coefs = vec![];
+    let mut depth = 0;
+    let mut coef = String::new();
+    for c in input.chars().chain([' ']) {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ => {}
+        }
+        if c.is_whitespace() && depth == 0 {
+            if !coef.is_empty() {
+                let k: Polynomial = coef.parse().map_err(|e| format!("invalid coefficient \"{coef}\": {e}"))?;
+                coefs.push(k.get_k_at(0));
+                coef.clear();
+            }
+        } else {
+            coef.push(c);
+        }
+    }
+    if coefs.is_empty() { return Err("no coefficients".to_string()); }
+    Ok(Polynomial::new(coefs))
+}
+
+
+/// Parses arguments, solves polynomial and prints roots, returns exit code.
+///
+/// Returns `None` if there are no arguments and stdin is terminal or empty, so there is nothing to solve.
+pub fn run(args: impl IntoIterator<Item = String>) -> Option<i32> {
+    let args: Vec<String> = args.into_iter().collect();
+    let has_args = !args.is_empty();
+    let args = match Args::parse(args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return Some(2);
+        }
+    };
+    if args.is_help {
+        println!("{USAGE}");
+        return Some(0);
+    }
+    let input = match args.input {
+        Some(input) => input,
+        None => {
+            let mut input = String::new();
+            if !io::stdin().is_terminal() {
+                if let Err(e) = io::stdin().read_to_string(&mut input) {
+                    eprintln!("can't read stdin: {e}");
+                    return Some(2);
+                }
+            }
+            if !has_args && input.trim().is_empty() { return None; }
+            input
+        }
+    };
+    let p = match parse_polynomial(&input) {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("{e}");
+            return Some(2);
+        }
+    };
+    let roots = args.solver.solve(&p);
+    print!("{}", format_roots(&p, &args.solver, &roots, args.format));
+    Some(if roots.is_converged { 0 } else { 1 })
+}
+
+
+pub fn format_roots(p: &Polynomial, solver: &Solver, roots: &Roots, format: Format) -> String {
+    let residuals: Vec<f64> = roots.roots.iter().map(|&z| p.eval_compensated_at(z).norm()).collect();
+    let rows = roots.roots.iter().zip(&roots.error_bounds).zip(&roots.inclusion_radii).zip(residuals);
+    let mut s = String::new();
+    match format {
+        Format::Human => {
+            s += &format!("Solving: {}\n", p.to_ascii());
+            s += &format!(
+                "by {:?}: iters = {}, {}\n",
+                solver.method, roots.iters, if roots.is_converged { "converged" } else { "NOT converged" },
+            );
+            for (((root, error_bound), inclusion_radius), residual) in rows {
+                s += &format!("{root} ± {error_bound:e}, inclusion radius = {inclusion_radius:e}, |p(z)| = {residual:e}\n");
+            }
+        }
+        Format::Csv => {
+            s += "re,im,error_bound,inclusion_radius,residual\n";
+            for (((root, error_bound), inclusion_radius), residual) in rows {
+                s += &format!("{},{},{error_bound:e},{inclusion_radius:e},{residual:e}\n", root.re, root.im);
+            }
+        }
+        Format::Json => {
+            // `to_ascii` has no chars, which must be escaped:
+            s += &format!(
+                "{{\"polynomial\": \"{}\", \"method\": \"{:?}\", \"iters\": {}, \"is_converged\": {}, \"roots\": [",
+                p.to_ascii(), solver.method, roots.iters, roots.is_converged,
+            );
+            let roots: Vec<String> = rows
+                .map(|(((root, &error_bound), &inclusion_radius), residual)| format!(
+                    "{{\"re\": {}, \"im\": {}, \"error_bound\": {}, \"inclusion_radius\": {}, \"residual\": {}}}",
+                    json_number(root.re), json_number(root.im), json_number(error_bound),
+                    json_number(inclusion_radius), json_number(residual),
+                ))
+                .collect();
+            s += &roots.join(", ");
+            s += "]}\n";
+        }
+    }
+    s
+}
+
+
+/// JSON has no `NaN` and `inf`.
+fn json_number(x: f64) -> String {
+    if x.is_finite() { format!("{x:?}") } else { "null".to_string() }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn parse_args() {
+        let a = args(&["-m", "companion", "--tolerance", "1e-10", "-f", "json", "-3", "0", "1"]).unwrap();
+        assert_eq!(Some("-3 0 1".to_string()), a.input);
+        assert_eq!(Solver::new(Method::CompanionMatrix).with_tolerance(1e-10), a.solver);
+        assert_eq!(Format::Json, a.format);
+        let a = args(&["--", "-x^2", "+", "1"]).unwrap();
+        assert_eq!(Some("-x^2 + 1".to_string()), a.input);
+        assert_eq!(Solver::default(), a.solver);
+        assert_eq!(None, args(&[]).unwrap().input);
+        assert!(args(&["-h"]).unwrap().is_help);
+        assert!(args(&["--method", "bisection"]).is_err());
+        assert!(args(&["-t", "-1"]).is_err());
+        assert!(args(&["-f"]).is_err());
+        // leading minus:
+        assert_eq!(Some("-x^2 + 1".to_string()), args(&["-x^2 + 1"]).unwrap().input);
+        assert_eq!(Some("-(1+2i) 0 1".to_string()), args(&["-(1+2i)", "0", "1"]).unwrap().input);
+        assert_eq!(Some("-i 1".to_string()), args(&["--", "-i", "1"]).unwrap().input);
+        assert_eq!(Some("--verbose".to_string()), args(&["--verbose"]).unwrap().input);
+    }
+
+    #[test]
+    fn parse_polynomial() {
+        assert_eq!(Ok(Polynomial::new([7, 2, 7, 2, 1])), super::parse_polynomial("x^4 + 2x^3 + 7x^2 + 2x + 7"));
+        assert_eq!(Ok(Polynomial::new([7, 2, 7, 2, 1])), super::parse_polynomial(" 7 2\n7 2 1\n"));
+        assert_eq!(
+            Ok(Polynomial::new(vec![(-3.).into(), 0.0.into(), num::complex::Complex64::new(1., 2.)])),
+            super::parse_polynomial("-3 0 (1 + 2i)"),
+        );
+        assert_eq!(Ok(Polynomial::new([1, 0, -1])), super::parse_polynomial("-x^2 + 1"));
+        assert_eq!(
+            Ok(Polynomial::new(vec![num::complex::Complex64::new(-1., -2.), 0.0.into(), 1.0.into()])),
+            super::parse_polynomial("-(1+2i) 0 1"),
+        );
+        assert!(super::parse_polynomial("1 two").is_err());
+        assert!(super::parse_polynomial("--verbose").is_err());
+        assert!(super::parse_polynomial("  ").is_err());
+    }
+
+    #[test]
+    fn format_roots() {
+        let p = Polynomial::new([-4, 0, 1]);
+        let solver = Solver::default();
+        let roots = solver.solve(&p);
+        // roots are exact, but inclusion radius accounts for rounding errors:
+        let r = roots.inclusion_radii[0];
+        assert!(0. < r && r < 1e-20);
+        assert_eq!(
+            format!(
+                "Solving: x^2 - 4\nby AberthEhrlich: iters = {}, converged\n\
+                -2+0i ± 0e0, inclusion radius = {r:e}, |p(z)| = 0e0\n2+0i ± 0e0, inclusion radius = {r:e}, |p(z)| = 0e0\n",
+                roots.iters,
+            ),
+            super::format_roots(&p, &solver, &roots, Format::Human),
+        );
+        assert_eq!(
+            format!("re,im,error_bound,inclusion_radius,residual\n-2,0,0e0,{r:e},0e0\n2,0,0e0,{r:e},0e0\n"),
+            super::format_roots(&p, &solver, &roots, Format::Csv),
+        );
+        assert_eq!(
+            format!(
+                "{{\"polynomial\": \"x^2 - 4\", \"method\": \"AberthEhrlich\", \"iters\": {}, \"is_converged\": true, \"roots\": \
+                [{{\"re\": -2.0, \"im\": 0.0, \"error_bound\": 0.0, \"inclusion_radius\": {r:?}, \"residual\": 0.0}}, \
+                {{\"re\": 2.0, \"im\": 0.0, \"error_bound\": 0.0, \"inclusion_radius\": {r:?}, \"residual\": 0.0}}]}}\n",
+                roots.iters,
+            ),
+            super::format_roots(&p, &solver, &roots, Format::Json),
+        );
+    }
+}
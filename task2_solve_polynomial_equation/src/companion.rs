@@ -0,0 +1,134 @@
+//! Companion matrix and its eigenvalues (which are roots of polynomial) by shifted QR algorithm.
+
+use num::{complex::Complex64, One, Zero};
+
+use crate::polynomial::Polynomial;
+
+
+impl Polynomial {
+    /// Companion matrix of monic `x^n + c_(n-1) x^(n-1) + … + c_0`: first row is `-c_(n-1) … -c_0`,
+    /// ones are under main diagonal, so it's upper Hessenberg and its eigenvalues are roots of polynomial.
+    pub fn companion_matrix(&self) -> Vec<Vec<Complex64>> {
+        let n = self.degree();
+        let k_last = self.get_k_last();
+        let mut m = vec![vec![Complex64::zero(); n]; n];
+        for (j, m_0j) in m[0].iter_mut().enumerate() {
+            *m_0j = -self.k[n-1-j] / k_last;
+        }
+        for i in 1..n {
+            m[i][i-1] = Complex64::one();
+        }
+        m
+    }
+}
+
+
+/// Eigenvalues of complex upper Hessenberg matrix `h` by QR algorithm with Wilkinson shifts and deflation.
+///
+/// Returns `(eigenvalues, iters, is_converged)`.
+pub(crate) fn eigenvalues_of_hessenberg(mut h: Vec<Vec<Complex64>>, max_iters: u32) -> (Vec<Complex64>, u32, bool) {
+    let n = h.len();
+    let mut eigenvalues = vec![Complex64::zero(); n];
+    if n == 0 { return (eigenvalues, 0, true); }
+    let mut iters = 0;
+    let mut iters_since_deflation = 0;
+    // active block is `lo..=hi`:
+    let mut hi = n - 1;
+    while hi > 0 {
+        // find start of active block, where subdiagonal element is negligible:
+        let mut lo = hi;
+        while lo > 0 && h[lo][lo-1].norm() > f64::EPSILON * (h[lo][lo].norm() + h[lo-1][lo-1].norm()) {
+            lo -= 1;
+        }
+        if lo > 0 { h[lo][lo-1] = Complex64::zero(); }
+        if lo == hi {
+            eigenvalues[hi] = h[hi][hi];
+            hi -= 1;
+            iters_since_deflation = 0;
+            continue;
+        }
+        if iters == max_iters { return (eigenvalues, iters, false); }
+        iters += 1;
+        iters_since_deflation += 1;
+        let mu = if iters_since_deflation % 10 == 0 {
+            // exceptional shift, to break cycles:
+            h[hi][hi] + h[hi][hi-1].norm() * Complex64::new(0.75, 0.5)
+        } else {
+            wilkinson_shift(h[hi-1][hi-1], h[hi-1][hi], h[hi][hi-1], h[hi][hi])
+        };
+        qr_step(&mut h, lo, hi, mu);
+    }
+    eigenvalues[0] = h[0][0];
+    (eigenvalues, iters, true)
+}
+
+
+/// Eigenvalue of `[[a, b], [c, d]]`, which is closer to `d`.
+fn wilkinson_shift(a: Complex64, b: Complex64, c: Complex64, d: Complex64) -> Complex64 {
+    let m = (a + d) / 2.;
+    let disc = ((a - d) * (a - d) / 4. + b * c).sqrt();
+    let (mu1, mu2) = (m + disc, m - disc);
+    if (mu1 - d).norm() < (mu2 - d).norm() { mu1 } else { mu2 }
+}
+
+
+/// One step `H - μI = QR`, `H = RQ + μI` on block `lo..=hi` by Givens rotations.
+#[allow(clippy::needless_range_loop)]
+fn qr_step(h: &mut [Vec<Complex64>], lo: usize, hi: usize, mu: Complex64) {
+    for k in lo..=hi { h[k][k] -= mu; }
+    let mut rotations = Vec::with_capacity(hi - lo);
+    for k in lo..hi {
+        let (x, y) = (h[k][k], h[k+1][k]);
+        let r = (x.norm_sqr() + y.norm_sqr()).sqrt();
+        let (c, s) = if r == 0. { (Complex64::one(), Complex64::zero()) } else { (x / r, y / r) };
+        // rows `k` and `k+1` are multiplied by `[[c̄, s̄], [-s, c]]`:
+        for j in k..=hi {
+            let (a, b) = (h[k][j], h[k+1][j]);
+            h[k][j] = c.conj() * a + s.conj() * b;
+            h[k+1][j] = -s * a + c * b;
+        }
+        rotations.push((c, s));
+    }
+    for (k, (c, s)) in (lo..hi).zip(rotations) {
+        // columns `k` and `k+1` are multiplied by `[[c, -s̄], [s, c̄]]`:
+        for i in lo..=(k+2).min(hi) {
+            let (a, b) = (h[i][k], h[i][k+1]);
+            h[i][k] = a * c + b * s;
+            h[i][k+1] = -a * s.conj() + b * c.conj();
+        }
+    }
+    for k in lo..=hi { h[k][k] += mu; }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn c(re: f64) -> Complex64 { re.into() }
+
+    #[test]
+    fn companion_matrix() {
+        // 2 (x² - 3x + 2):
+        let m = Polynomial::new([4, -6, 2]).companion_matrix();
+        assert_eq!(vec![vec![c(3.), c(-2.)], vec![c(1.), c(0.)]], m);
+    }
+
+    #[test]
+    fn eigenvalues() {
+        // (x - 2)(x - 3)(x - 5)(x - 7):
+        let (mut eigenvalues, _, is_converged) = eigenvalues_of_hessenberg(Polynomial::new([210, -247, 101, -17, 1]).companion_matrix(), 1000);
+        assert!(is_converged);
+        eigenvalues.sort_by(|a, b| a.re.total_cmp(&b.re));
+        for (eigenvalue, root) in eigenvalues.into_iter().zip([2., 3., 5., 7.]) {
+            assert!((eigenvalue - root).norm() < 1e-10);
+        }
+        // rotation by 90°, eigenvalues are ±i:
+        let (eigenvalues, _, _) = eigenvalues_of_hessenberg(vec![vec![c(0.), c(-1.)], vec![c(1.), c(0.)]], 1000);
+        assert!(eigenvalues.iter().any(|e| (e - Complex64::i()).norm() < 1e-12));
+        assert!(eigenvalues.iter().any(|e| (e + Complex64::i()).norm() < 1e-12));
+    }
+}
@@ -0,0 +1,84 @@
+//! Compensated Horner's scheme: result is as accurate as if it was computed in doubled precision and then rounded.
+//!
+//! Rounding errors of every `+` and `*` are found exactly by error-free transformations and summed separately,
+//! see Graillat, Langlois, Louvet "Algorithms for accurate, validated and fast polynomial evaluation".
+
+use num::{complex::Complex64, Zero};
+
+use crate::polynomial::Polynomial;
+
+
+impl Polynomial {
+    /// Evaluates by compensated Horner's scheme, which is needed near roots, where usual Horner's scheme
+    /// has rounding errors bigger than value itself.
+    pub fn eval_compensated_at(&self, x: impl Into<Complex64>) -> Complex64 {
+        let x: Complex64 = x.into();
+        let mut s = self.get_k_last();
+        let mut error = Complex64::zero();
+        for k in self.k.iter().rev().skip(1) {
+            let (p, p_error) = two_prod_complex(s, x);
+            let (s_new, s_error) = two_sum_complex(p, *k);
+            s = s_new;
+            error = error * x + (p_error + s_error);
+        }
+        s + error
+    }
+}
+
+
+/// `a + b = s + e` exactly.
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let b_virtual = s - a;
+    let e = (a - (s - b_virtual)) + (b - b_virtual);
+    (s, e)
+}
+
+/// `a * b = p + e` exactly.
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+fn two_sum_complex(a: Complex64, b: Complex64) -> (Complex64, Complex64) {
+    let (re, re_error) = two_sum(a.re, b.re);
+    let (im, im_error) = two_sum(a.im, b.im);
+    (Complex64::new(re, im), Complex64::new(re_error, im_error))
+}
+
+/// `a * b = p + e`, where `e` is rounding error, computed up to rounding of itself.
+fn two_prod_complex(a: Complex64, b: Complex64) -> (Complex64, Complex64) {
+    let (re1, re1_error) = two_prod(a.re, b.re);
+    let (re2, re2_error) = two_prod(a.im, b.im);
+    let (im1, im1_error) = two_prod(a.re, b.im);
+    let (im2, im2_error) = two_prod(a.im, b.re);
+    let (re, re_error) = two_sum(re1, -re2);
+    let (im, im_error) = two_sum(im1, im2);
+    (
+        Complex64::new(re, im),
+        Complex64::new(re1_error - re2_error + re_error, im1_error + im2_error + im_error),
+    )
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eval_compensated_at() {
+        // (x - 1)⁵ near `x = 1`, where usual Horner's scheme gives mostly rounding errors:
+        let p = Polynomial::new([-1, 5, -10, 10, -5, 1]);
+        let x = 1. + 1e-3;
+        let exact = 1e-15;
+        assert!((p.eval_compensated_at(x).re - exact).abs() < 1e-3 * exact);
+        assert!((p.eval_at(x).re - exact).abs() > 1e-3 * exact);
+        // complex:
+        let x = Complex64::new(1., 1e-3);
+        let exact = Complex64::new(0., 1e-15);
+        assert!((p.eval_compensated_at(x) - exact).norm() < 1e-3 * exact.norm());
+    }
+}
@@ -0,0 +1,316 @@
+//! Double-double floating point number: unevaluated sum `hi + lo` of two `f64`, about 32 significant digits.
+//!
+//! Arithmetic is built on error-free transformations of [compensated](crate::compensated) Horner's scheme,
+//! see Hida, Li, Bailey "Library for double-double and quad-double arithmetic".
+
+use std::{
+    error::Error,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+    str::FromStr,
+};
+
+use num::{FromPrimitive, Num, One, ToPrimitive, Zero};
+
+use crate::compensated::{two_prod, two_sum};
+
+
+/// Comparison is lexicographic by `(hi, lo)`, which is correct, because `hi` is rounded `hi + lo`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    /// Relative precision `2^-104`.
+    pub const EPSILON: f64 = f64::EPSILON * f64::EPSILON;
+
+    /// Normalizes `hi + lo`.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        Self::from_parts(hi, lo)
+    }
+
+    pub fn hi(&self) -> f64 { self.hi }
+    pub fn lo(&self) -> f64 { self.lo }
+
+    pub fn is_finite(&self) -> bool { self.hi.is_finite() }
+
+    pub fn abs(self) -> Self {
+        if self.hi < 0. { -self } else { self }
+    }
+
+    pub fn floor(self) -> Self {
+        let hi = self.hi.floor();
+        // if `hi` is integer, then fraction is in `lo`:
+        if hi == self.hi { Self::new(hi, self.lo.floor()) } else { Self::from(hi) }
+    }
+
+    pub fn trunc(self) -> Self {
+        if self.hi < 0. { -(-self).floor() } else { self.floor() }
+    }
+
+    /// `10^n`.
+    fn pow10(n: i32) -> Self {
+        let (mut result, mut base, mut m) = (Self::one(), Self::from(10.), n.unsigned_abs());
+        while m > 0 {
+            if m % 2 == 1 { result = result * base; }
+            base = base * base;
+            m /= 2;
+        }
+        if n < 0 { Self::one() / result } else { result }
+    }
+
+    /// `hi + lo = s + e`, where `|hi| >= |lo|`, without normalization, which breaks infinities.
+    fn from_parts(hi: f64, lo: f64) -> Self {
+        if hi.is_finite() { Self { hi, lo } } else { Self { hi, lo: 0. } }
+    }
+}
+
+
+/// `a + b = s + e` exactly, if `|a| >= |b|`.
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let s = a + b;
+    DoubleDouble::from_parts(s, b - (s - a))
+}
+
+
+impl From<f64> for DoubleDouble {
+    fn from(x: f64) -> Self {
+        Self { hi: x, lo: 0. }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self { hi: -self.hi, lo: -self.lo }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (s, e) = two_sum(self.hi, rhs.hi);
+        let (t, f) = two_sum(self.lo, rhs.lo);
+        let DoubleDouble { hi: s, lo: e } = quick_two_sum(s, e + t);
+        quick_two_sum(s, e + f)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let (p, e) = two_prod(self.hi, rhs.hi);
+        quick_two_sum(p, e + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+    /// Long division: every next quotient digit is `f64`.
+    fn div(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs * Self::from(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * Self::from(q2);
+        let q3 = r.hi / rhs.hi;
+        quick_two_sum(q1, q2) + Self::from(q3)
+    }
+}
+
+impl Rem for DoubleDouble {
+    type Output = Self;
+    /// Like `f64`, result has sign of `self`.
+    fn rem(self, rhs: Self) -> Self {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+
+impl Zero for DoubleDouble {
+    fn zero() -> Self { Self::from(0.) }
+    fn is_zero(&self) -> bool { self.hi == 0. }
+}
+
+impl One for DoubleDouble {
+    fn one() -> Self { Self::from(1.) }
+}
+
+impl Num for DoubleDouble {
+    type FromStrRadixErr = ParseDoubleDoubleError;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 { return Err(ParseDoubleDoubleError); }
+        s.parse()
+    }
+}
+
+impl FromPrimitive for DoubleDouble {
+    fn from_i64(n: i64) -> Option<Self> { Self::from_i128(n as i128) }
+    fn from_u64(n: u64) -> Option<Self> { Self::from_i128(n as i128) }
+
+    /// Exact for `|n| < 2^106`.
+    fn from_i128(n: i128) -> Option<Self> {
+        let hi = n as f64;
+        Some(Self::new(hi, n.wrapping_sub(hi as i128) as f64))
+    }
+
+    fn from_f64(x: f64) -> Option<Self> { Some(Self::from(x)) }
+}
+
+impl ToPrimitive for DoubleDouble {
+    fn to_i64(&self) -> Option<i64> { self.to_i128().and_then(|n| n.try_into().ok()) }
+    fn to_u64(&self) -> Option<u64> { self.to_i128().and_then(|n| n.try_into().ok()) }
+
+    /// Truncated.
+    fn to_i128(&self) -> Option<i128> {
+        let t = self.trunc();
+        if !t.is_finite() || t.hi.abs() >= 2f64.powi(126) { return None; }
+        Some(t.hi as i128 + t.lo as i128)
+    }
+
+    fn to_f64(&self) -> Option<f64> { Some(self.hi) }
+}
+
+
+/// Decimal number with optional sign, fraction and exponent, like `-1.25e-3`, `inf` and `NaN`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDoubleDoubleError;
+
+impl fmt::Display for ParseDoubleDoubleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid double-double number")
+    }
+}
+
+impl Error for ParseDoubleDoubleError {}
+
+impl FromStr for DoubleDouble {
+    type Err = ParseDoubleDoubleError;
+
+    /// Digits are accumulated exactly (up to 32 of them), so result is correctly rounded in most cases,
+    /// unlike parsing to `f64`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let x: f64 = s.parse().map_err(|_| ParseDoubleDoubleError)?;
+        if !x.is_finite() { return Ok(Self::from(x)); }
+        let (mantissa, exponent) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], s[i+1..].parse::<i32>().map_err(|_| ParseDoubleDoubleError)?),
+            None => (s, 0),
+        };
+        let (is_negative, mantissa) = match mantissa.strip_prefix('-') {
+            Some(mantissa) => (true, mantissa),
+            None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+        };
+        let mut value = Self::zero();
+        let mut exponent = exponent;
+        let mut is_fraction = false;
+        for c in mantissa.chars() {
+            match c {
+                '.' => is_fraction = true,
+                d => {
+                    value = value * Self::from(10.) + Self::from(d.to_digit(10).ok_or(ParseDoubleDoubleError)? as f64);
+                    if is_fraction { exponent -= 1; }
+                }
+            }
+        }
+        let value = if exponent < 0 { value / Self::pow10(-exponent) } else { value * Self::pow10(exponent) };
+        Ok(if is_negative { -value } else { value })
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    /// 32 significant digits, without trailing zeros, exponential notation is for very big and small numbers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const DIGITS: usize = 32;
+        if !self.is_finite() { return write!(f, "{}", self.hi); }
+        if self.is_zero() { return write!(f, "0"); }
+        if self.hi < 0. { write!(f, "-")?; }
+        let x = self.abs();
+        let mut exponent = x.hi.log10().floor() as i32;
+        let mut x = x / Self::pow10(exponent);
+        if x.hi >= 10. { x = x / Self::from(10.); exponent += 1; }
+        if x.hi < 1. { x = x * Self::from(10.); exponent -= 1; }
+        let mut digits: Vec<u8> = Vec::with_capacity(DIGITS + 1);
+        for _ in 0..=DIGITS {
+            let d = x.floor().hi.clamp(0., 9.);
+            digits.push(d as u8);
+            x = (x - Self::from(d)) * Self::from(10.);
+        }
+        // rounding by last digit:
+        if digits.pop().unwrap() >= 5 {
+            let mut i = DIGITS;
+            while i > 0 && digits[i-1] == 9 { digits[i-1] = 0; i -= 1; }
+            if i == 0 { digits.insert(0, 1); digits.pop(); exponent += 1; } else { digits[i-1] += 1; }
+        }
+        while digits.len() > 1 && *digits.last().unwrap() == 0 { digits.pop(); }
+        let digits: String = digits.into_iter().map(|d| char::from(b'0' + d)).collect();
+        match exponent {
+            e @ 0..=31 => {
+                let e = e as usize;
+                if digits.len() <= e + 1 {
+                    write!(f, "{digits}{}", "0".repeat(e + 1 - digits.len()))
+                } else {
+                    write!(f, "{}.{}", &digits[..=e], &digits[e+1..])
+                }
+            }
+            e @ -5..=-1 => write!(f, "0.{}{digits}", "0".repeat((-e - 1) as usize)),
+            e if digits.len() == 1 => write!(f, "{digits}e{e}"),
+            e => write!(f, "{}.{}e{e}", &digits[..1], &digits[1..]),
+        }
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dd(s: &str) -> DoubleDouble {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let third = DoubleDouble::one() / DoubleDouble::from(3.);
+        assert!((third * DoubleDouble::from(3.) - DoubleDouble::one()).abs().hi() < 1e-31);
+        // `0.1` isn't representable in `f64`, but is much more accurate in double-double:
+        assert!((dd("0.1") * DoubleDouble::from(10.) - DoubleDouble::one()).abs().hi() < 1e-31);
+        assert_ne!(0., dd("0.1").lo());
+        // `1 + 2^-80` is lost in `f64`:
+        let tiny = DoubleDouble::from(2f64.powi(-80));
+        assert_eq!(tiny, (DoubleDouble::one() + tiny) - DoubleDouble::one());
+        assert_eq!(DoubleDouble::from(1.5), DoubleDouble::from(7.5) % DoubleDouble::from(3.));
+        assert_eq!(DoubleDouble::from(-3.), dd("-3.75").trunc());
+        assert!(dd("1e-20") < dd("1.00000000000000000001e-20"));
+    }
+
+    #[test]
+    fn conversions() {
+        let n: i128 = (1 << 100) + 12345;
+        assert_eq!(Some(n), DoubleDouble::from_i128(n).unwrap().to_i128());
+        assert_eq!(Some(-7), dd("-7.9").to_i64());
+        assert_eq!(None, dd("-1").to_u64());
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!("0.33333333333333333333333333333333", (DoubleDouble::one() / DoubleDouble::from(3.)).to_string());
+        assert_eq!("-20", DoubleDouble::from(-20.).to_string());
+        assert_eq!("1.5", DoubleDouble::from(1.5).to_string());
+        assert_eq!("0.001", dd("0.001").to_string());
+        assert_eq!("1e40", dd("1e40").to_string());
+        assert_eq!("1.2345678901234567890123456789012e-10", dd("1.2345678901234567890123456789012e-10").to_string());
+        assert_eq!("0", DoubleDouble::zero().to_string());
+    }
+}
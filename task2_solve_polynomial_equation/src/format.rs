@@ -0,0 +1,98 @@
+//! Formatting of polynomials as LaTeX and plain ASCII, in conventional order from highest power.
+//!
+//! Unlike [`Display`](std::fmt::Display), zero terms are skipped and unit coefficients are omitted.
+
+use num::{complex::Complex64, Zero};
+
+use crate::polynomial::Polynomial;
+
+
+pub(crate) const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+/// `12` => `¹²`.
+pub(crate) fn superscript(n: usize) -> String {
+    n.to_string().chars().map(|d| SUPERSCRIPT_DIGITS[d.to_digit(10).unwrap() as usize]).collect()
+}
+
+
+impl Polynomial {
+    /// Like `2x^{3} - (1+2i)x + 7`.
+    pub fn to_latex(&self) -> String {
+        self.format_with(|coef, i| match i {
+            0 => coef.unwrap_or("1").to_string(),
+            1 => format!("{}x", coef.unwrap_or("")),
+            i => format!("{}x^{{{i}}}", coef.unwrap_or("")),
+        })
+    }
+
+    /// Like `2*x^3 - (1+2i)*x + 7`.
+    pub fn to_ascii(&self) -> String {
+        self.format_with(|coef, i| match (coef, i) {
+            (coef, 0) => coef.unwrap_or("1").to_string(),
+            (None, 1) => "x".to_string(),
+            (Some(coef), 1) => format!("{coef}*x"),
+            (None, i) => format!("x^{i}"),
+            (Some(coef), i) => format!("{coef}*x^{i}"),
+        })
+    }
+
+    /// Joins non-zero terms by signs, `term(coef, i)` formats term with absolute value of real coefficient
+    /// or complex one in parentheses, `coef` is `None` if it's `1`.
+    fn format_with(&self, term: impl Fn(Option<&str>, usize) -> String) -> String {
+        let mut s = String::new();
+        for (i, &k) in self.k.iter().enumerate().rev().filter(|(_, k)| !k.is_zero()) {
+            let (is_negative, coef) = format_coef(k);
+            let coef = if coef == "1" { None } else { Some(coef.as_str()) };
+            let sign = match (s.is_empty(), is_negative) {
+                (true, true) => "-",
+                (true, false) => "",
+                (false, true) => " - ",
+                (false, false) => " + ",
+            };
+            s += sign;
+            s += &term(coef, i);
+        }
+        if s.is_empty() { "0".to_string() } else { s }
+    }
+}
+
+
+/// Returns `(is_negative, abs)` for real or imaginary `k`, and `(false, "(re±im i)")` for complex one.
+fn format_coef(k: Complex64) -> (bool, String) {
+    if k.im == 0. {
+        (k.re < 0., k.re.abs().to_string())
+    } else if k.re == 0. {
+        let im = k.im.abs();
+        (k.im < 0., if im == 1. { "i".to_string() } else { format!("{im}i") })
+    } else {
+        (false, format!("({k})"))
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_latex_and_ascii() {
+        let p = Polynomial::new([7, 2, 7, 2, 1]);
+        assert_eq!("x^{4} + 2x^{3} + 7x^{2} + 2x + 7", p.to_latex());
+        assert_eq!("x^4 + 2*x^3 + 7*x^2 + 2*x + 7", p.to_ascii());
+        let p = Polynomial::new(vec![(-3.).into(), Complex64::new(0., -1.), Complex64::new(1., 2.), 0.0.into(), (-1.5).into()]);
+        assert_eq!("-1.5x^{4} + (1+2i)x^{2} - ix - 3", p.to_latex());
+        assert_eq!("-1.5*x^4 + (1+2i)*x^2 - i*x - 3", p.to_ascii());
+        assert_eq!("0", Polynomial::zero().to_latex());
+        assert_eq!("1", Polynomial::one().to_ascii());
+        assert_eq!("-x^{12}", Polynomial::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1]).to_latex());
+    }
+
+    #[test]
+    fn superscript() {
+        assert_eq!("²", super::superscript(2));
+        assert_eq!("¹⁰⁷", super::superscript(107));
+    }
+}
@@ -0,0 +1,171 @@
+//! Constructors of polynomial from its roots, by interpolation through points and by least-squares fit.
+
+use num::complex::Complex64;
+
+use crate::{polynomial::Polynomial, scalar::Scalar};
+
+
+impl<T: Scalar> Polynomial<T> {
+    /// `∏ (x - r_i)`, it's `1` if there are no roots.
+    pub fn from_roots(roots: impl IntoIterator<Item = T>) -> Self {
+        roots.into_iter().fold(Self::from_coefs(vec![T::one()]), |p, r| &p * &Self::from_coefs(vec![-r, T::one()]))
+    }
+
+    /// Lagrange's form: `Σ y_i ∏_(j≠i) (x - x_j) / (x_i - x_j)`, all `x_i` must be distinct.
+    ///
+    /// Result is unique polynomial of degree `< n`, which goes through `n` points, so it's same as
+    /// [`interpolate_newton`](Self::interpolate_newton), but it's `O(n³)`.
+    pub fn interpolate_lagrange(points: &[(T, T)]) -> Self {
+        let mut p = Self::from_coefs(vec![T::zero()]);
+        for (i, (x_i, y_i)) in points.iter().enumerate() {
+            let mut basis = Self::from_coefs(vec![y_i.clone()]);
+            for (j, (x_j, _)) in points.iter().enumerate().filter(|&(j, _)| j != i) {
+                assert!(x_i != x_j, "interpolation points {i} and {j} have same x");
+                let d = x_i.clone() - x_j.clone();
+                basis = &basis * &Self::from_coefs(vec![-x_j.clone() / d.clone(), T::one() / d]);
+            }
+            p = &p + &basis;
+        }
+        p
+    }
+
+    /// Newton's form: `c_0 + c_1 (x - x_0) + c_2 (x - x_0)(x - x_1) + …`, where `c_i = y[x_0, …, x_i]`
+    /// are divided differences, all `x_i` must be distinct. It's `O(n²)`.
+    pub fn interpolate_newton(points: &[(T, T)]) -> Self {
+        let n = points.len();
+        // divided differences in place: after step `k`, `c[i] = y[x_(i-k), …, x_i]` for `i >= k`:
+        let mut c: Vec<T> = points.iter().map(|(_, y)| y.clone()).collect();
+        for k in 1..n {
+            for i in (k..n).rev() {
+                let d = points[i].0.clone() - points[i-k].0.clone();
+                assert!(!d.is_zero(), "interpolation points {} and {i} have same x", i - k);
+                c[i] = (c[i].clone() - c[i-1].clone()) / d;
+            }
+        }
+        // by Horner's scheme for Newton's form:
+        c.into_iter().zip(points).rev().fold(Self::from_coefs(vec![T::zero()]), |p, (c_i, (x_i, _))| {
+            &(&p * &Self::from_coefs(vec![-x_i.clone(), T::one()])) + &Self::from_coefs(vec![c_i])
+        })
+    }
+}
+
+
+impl Polynomial {
+    /// Polynomial of given `degree` with real coefficients, which minimizes `Σ (p(x_i) - y_i)²`.
+    ///
+    /// `x` is scaled to `[-1, 1]`, and least-squares problem for Vandermonde matrix is solved by Householder QR
+    /// (not by normal equations, which square condition number).
+    pub fn fit_least_squares(points: &[(f64, f64)], degree: usize) -> Self {
+        assert!(points.len() > degree, "{} points are not enough for degree {degree}", points.len());
+        let min = points.iter().map(|&(x, _)| x).fold(f64::INFINITY, f64::min);
+        let max = points.iter().map(|&(x, _)| x).fold(f64::NEG_INFINITY, f64::max);
+        let center = (min + max) / 2.;
+        let scale = if max > min { (max - min) / 2. } else { 1. };
+        let a: Vec<Vec<f64>> = points.iter()
+            .map(|&(x, _)| {
+                let t = (x - center) / scale;
+                std::iter::successors(Some(1.), |t_i| Some(t_i * t)).take(degree + 1).collect()
+            })
+            .collect();
+        let b: Vec<f64> = points.iter().map(|&(_, y)| y).collect();
+        let q = Polynomial::new(solve_least_squares(a, b).into_iter().map(Complex64::from).collect::<Vec<_>>());
+        // p(x) = q((x - center) / scale):
+        q.compose(&Polynomial::new([-center / scale, 1. / scale]))
+    }
+}
+
+
+/// Minimizes `|a x - b|` for `m × n` matrix `a`, `m >= n`, of full rank, by Householder QR.
+#[allow(clippy::needless_range_loop)]
+fn solve_least_squares(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Vec<f64> {
+    let (m, n) = (a.len(), a[0].len());
+    for k in 0..n {
+        // reflection `I - 2 v vᵀ / (vᵀ v)` zeroes column `k` under diagonal:
+        let norm = (k..m).map(|i| a[i][k] * a[i][k]).sum::<f64>().sqrt();
+        if norm == 0. { continue; }
+        let alpha = if a[k][k] > 0. { -norm } else { norm };
+        let mut v: Vec<f64> = (k..m).map(|i| a[i][k]).collect();
+        v[0] -= alpha;
+        let v_norm_sqr: f64 = v.iter().map(|v| v * v).sum();
+        for j in k..n {
+            let s = 2. * (k..m).map(|i| v[i-k] * a[i][j]).sum::<f64>() / v_norm_sqr;
+            for i in k..m { a[i][j] -= s * v[i-k]; }
+        }
+        let s = 2. * (k..m).map(|i| v[i-k] * b[i]).sum::<f64>() / v_norm_sqr;
+        for i in k..m { b[i] -= s * v[i-k]; }
+    }
+    // back substitution for `r x = qᵀ b`:
+    let mut x = vec![0.; n];
+    for i in (0..n).rev() {
+        let s: f64 = (i+1..n).map(|j| a[i][j] * x[j]).sum();
+        x[i] = (b[i] - s) / a[i][i];
+    }
+    x
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use num::BigRational;
+
+    use super::*;
+
+    #[test]
+    fn from_roots() {
+        let roots = [2., 3., 5., 7.].map(Complex64::from);
+        let p = Polynomial::from_roots(roots);
+        assert_eq!(Polynomial::new([210, -247, 101, -17, 1]), p);
+        assert_eq!(roots.to_vec(), p.find_all_solutions());
+        // complex roots:
+        let roots = [Complex64::new(-1., -2.), Complex64::new(0.5, 0.), Complex64::new(3., 1.)];
+        let found = Polynomial::from_roots(roots).find_all_solutions();
+        for (root, found) in roots.into_iter().zip(found) {
+            assert!((root - found).norm() < 1e-14);
+        }
+        assert_eq!(Polynomial::one(), Polynomial::from_roots([]));
+    }
+
+    #[test]
+    fn interpolate() {
+        // 7 + 2x + 7x² + 2x³ + x⁴:
+        let p = Polynomial::new([7, 2, 7, 2, 1]);
+        let points: Vec<(Complex64, Complex64)> = [-2., -0.5, 0., 1., 3.].into_iter()
+            .map(|x| (x.into(), p.eval_at(x)))
+            .collect();
+        for q in [Polynomial::interpolate_lagrange(&points), Polynomial::interpolate_newton(&points)] {
+            assert!((&q - &p).norm_inf() < 1e-12, "{q}");
+        }
+        // exact in rationals, 1/x at 1, 2, 4 is (x² - 7x + 14) / 8:
+        let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+        let points = [(r(1, 1), r(1, 1)), (r(2, 1), r(1, 2)), (r(4, 1), r(1, 4))];
+        let expected = Polynomial::from_coefs(vec![r(14, 8), r(-7, 8), r(1, 8)]);
+        assert_eq!(expected, Polynomial::interpolate_lagrange(&points));
+        assert_eq!(expected, Polynomial::interpolate_newton(&points));
+    }
+
+    #[test]
+    #[should_panic(expected = "same x")]
+    fn interpolate_same_x() {
+        Polynomial::interpolate_newton(&[(1., 2.), (1., 3.)]);
+    }
+
+    #[test]
+    fn fit_least_squares() {
+        // exact data is fitted exactly:
+        let p = Polynomial::new([-3., 0.5, 2.]);
+        let points: Vec<(f64, f64)> = (0..10).map(|i| i as f64 * 10.).map(|x| (x, p.eval_at(x).re)).collect();
+        assert!((&Polynomial::fit_least_squares(&points, 2) - &p).norm_inf() < 1e-9);
+        // line through noisy points `2x + 1 ± 0.1` is same as by linear regression formulas:
+        // slope = Σ (x - x̄)(y - ȳ) / Σ (x - x̄)² = 10.2 / 5, intercept = ȳ - slope x̄ = 4 - 2.04 * 1.5:
+        let points = [(0., 0.9), (1., 3.1), (2., 4.9), (3., 7.1)];
+        let line = Polynomial::fit_least_squares(&points, 1);
+        assert!((&line - &Polynomial::new([0.94, 2.04])).norm_inf() < 1e-12, "{line}");
+        // degree is `n - 1`, so it's interpolation:
+        let points = [(1., 1.), (2., 0.5), (4., 0.25)];
+        let q = Polynomial::fit_least_squares(&points, 2);
+        assert!((&q - &Polynomial::new([14. / 8., -7. / 8., 1. / 8.])).norm_inf() < 1e-12);
+    }
+}
@@ -0,0 +1,31 @@
+//! Library for solving polynomial equations.
+
+pub mod arithmetic;
+pub mod bounds;
+pub mod calculus;
+pub mod companion;
+pub mod compensated;
+pub mod double_double;
+pub mod format;
+pub mod interpolation;
+pub mod multiplicity;
+pub mod my_into;
+pub mod orthogonal;
+pub mod parse;
+pub mod polishing;
+pub mod polynomial;
+pub mod refinement;
+pub mod scalar;
+pub mod solvers;
+pub mod sturm;
+pub mod tracking;
+
+pub use crate::{
+    double_double::DoubleDouble,
+    my_into::MyInto,
+    parse::ParsePolynomialError,
+    polynomial::Polynomial,
+    scalar::{RealScalar, Scalar},
+    solvers::{Method, Roots, Solver},
+    tracking::RootLocus,
+};
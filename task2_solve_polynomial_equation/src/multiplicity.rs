@@ -0,0 +1,168 @@
+//! Multiple roots: square-free factorization and clustering of close roots.
+
+use num::complex::Complex64;
+
+use crate::{
+    polynomial::Polynomial,
+    solvers::{Roots, Solver},
+};
+
+
+impl Polynomial {
+    /// Yun's algorithm: returns `[(f_i, i), …]`, such that `p = a_n ∏ f_i^i`,
+    /// where `f_i` are monic, square-free and pairwise coprime. Constant factors are omitted.
+    pub fn square_free_factorization(&self) -> Vec<(Polynomial, usize)> {
+        let mut factors = vec![];
+        if self.degree() == 0 { return factors; }
+        let d = self.derivative();
+        let a = self.gcd(&d);
+        let mut b = self / &a;
+        let mut c = &d / &a;
+        let mut multiplicity = 1;
+        while b.degree() > 0 {
+            let b_derivative = b.derivative();
+            let d = (&c - &b_derivative).trimmed_by(Self::GCD_TOLERANCE * c.norm_inf().max(b_derivative.norm_inf()));
+            let a = b.gcd(&d);
+            if a.degree() > 0 {
+                factors.push((a.clone(), multiplicity));
+            }
+            b = &b / &a;
+            c = &d / &a;
+            multiplicity += 1;
+        }
+        factors
+    }
+
+    /// Distinct roots with multiplicities, see [`Solver::solve_distinct`].
+    pub fn find_distinct_solutions(&self) -> Vec<(Complex64, usize)> {
+        Solver::default().solve_distinct(self)
+    }
+}
+
+
+impl Solver {
+    /// Finds distinct roots with multiplicities.
+    ///
+    /// Every [square-free factor](Polynomial::square_free_factorization) is solved separately,
+    /// so its roots are simple and are found accurately. Factorization is done with tolerance, so it can merge
+    /// close simple roots, therefore every multiple root is verified: roots of `p` found directly near it must form
+    /// [cluster](Roots::clustered) of the same size. Otherwise directly found roots are clustered.
+    pub fn solve_distinct(&self, p: &Polynomial) -> Vec<(Complex64, usize)> {
+        let mut roots: Vec<(Complex64, usize)> = p.square_free_factorization().into_iter()
+            .flat_map(|(factor, multiplicity)| {
+                self.solve(&factor).clustered().into_iter()
+                    .map(move |(root, cluster_size)| (root, cluster_size * multiplicity))
+            })
+            .collect();
+        let roots_direct = self.solve(p);
+        let clusters = roots_direct.clusters();
+        let is_verified = roots.iter().all(|&(root, multiplicity)| {
+            multiplicity == 1 || clusters.iter().any(|cluster| {
+                cluster.len() == multiplicity
+                    && cluster.iter().any(|&i| (root - roots_direct.roots[i]).norm() <= roots_direct.inclusion_radii[i])
+            })
+        });
+        if !is_verified {
+            roots = roots_direct.clustered();
+        }
+        roots.sort_by(|(a, _), (b, _)| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        roots
+    }
+}
+
+
+impl Roots {
+    /// Groups roots, whose [inclusion discs](Self::inclusion_radii) overlap, so they can't be separated:
+    /// returns centroid of every group and its size, which is number of roots in union of its discs.
+    ///
+    /// Near root of multiplicity `m` its `m` approximations are spread around it, and their centroid
+    /// is much more accurate than each of them.
+    pub fn clustered(&self) -> Vec<(Complex64, usize)> {
+        self.clusters().into_iter()
+            .map(|cluster| {
+                let centroid = cluster.iter().map(|&i| self.roots[i]).sum::<Complex64>() / cluster.len() as f64;
+                (centroid, cluster.len())
+            })
+            .collect()
+    }
+
+    /// Indices of roots in connected components of union of inclusion discs.
+    fn clusters(&self) -> Vec<Vec<usize>> {
+        let n = self.roots.len();
+        // cluster of every root, clusters are merged by relabeling:
+        let mut cluster: Vec<usize> = (0..n).collect();
+        for i in 0..n {
+            for j in i+1..n {
+                let distance = (self.roots[i] - self.roots[j]).norm();
+                if distance <= self.inclusion_radii[i] + self.inclusion_radii[j] && cluster[i] != cluster[j] {
+                    let (from, to) = (cluster[j], cluster[i]);
+                    for c in cluster.iter_mut().filter(|c| **c == from) { *c = to; }
+                }
+            }
+        }
+        (0..n)
+            .map(|label| (0..n).filter(|&i| cluster[i] == label).collect::<Vec<usize>>())
+            .filter(|members| !members.is_empty())
+            .collect()
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::Method;
+
+    fn c(re: f64, im: f64) -> Complex64 { Complex64::new(re, im) }
+
+    fn assert_distinct_roots(expected: &[(Complex64, usize)], actual: &[(Complex64, usize)], tolerance: f64) {
+        assert_eq!(expected.len(), actual.len(), "{actual:?}");
+        for ((expected_root, expected_multiplicity), (root, multiplicity)) in expected.iter().zip(actual) {
+            assert!((expected_root - root).norm() < tolerance, "expected: {expected_root}, got: {root}");
+            assert_eq!(expected_multiplicity, multiplicity);
+        }
+    }
+
+    #[test]
+    fn square_free_factorization() {
+        // (x + 1)²:
+        assert_eq!(vec![(Polynomial::new([1, 1]), 2)], Polynomial::new([1, 2, 1]).square_free_factorization());
+        // 2 (x - 1)³ (x + 2):
+        let p = &(&Polynomial::new([-1, 3, -3, 1]) * &Polynomial::new([2, 1])) * &Polynomial::monomial(2.);
+        let factors = p.square_free_factorization();
+        assert_eq!(2, factors.len());
+        assert_eq!((Polynomial::new([2, 1]), 1), factors[0]);
+        assert_eq!(3, factors[1].1);
+        assert!((&factors[1].0 - &Polynomial::new([-1, 1])).norm_inf() < 1e-12);
+        // square-free:
+        assert_eq!(vec![(Polynomial::new([-6, 1, 1]), 1)], Polynomial::new([-6, 1, 1]).square_free_factorization());
+    }
+
+    #[test]
+    fn find_distinct_solutions() {
+        assert_eq!(vec![(c(-1., 0.), 2)], Polynomial::new([1, 2, 1]).find_distinct_solutions());
+        // (x² + 1)² (x - 3) = x⁵ - 3x⁴ + 2x³ - 6x² + x - 3:
+        assert_distinct_roots(
+            &[(c(0., -1.), 2), (c(0., 1.), 2), (c(3., 0.), 1)],
+            &Polynomial::new([-3, 1, -6, 2, -3, 1]).find_distinct_solutions(),
+            1e-12,
+        );
+        // (x - 2)⁴ (x + 1):
+        let p = &Polynomial::new([16, -32, 24, -8, 1]) * &Polynomial::new([1, 1]);
+        assert_distinct_roots(&[(c(-1., 0.), 1), (c(2., 0.), 4)], &p.find_distinct_solutions(), 1e-12);
+        // (x - 1)(x - 1 - 1e-5): roots are close, but separable, so they aren't merged:
+        let p = &Polynomial::new([-1, 1]) * &Polynomial::new([-1. - 1e-5, 1.]);
+        assert_distinct_roots(&[(c(1., 0.), 1), (c(1. + 1e-5, 0.), 1)], &p.find_distinct_solutions(), 1e-10);
+    }
+
+    #[test]
+    fn clustered() {
+        // (x - 1)³, solved directly, gives three approximations spread around `1`:
+        let roots = Solver::new(Method::AberthEhrlich).solve(&Polynomial::new([-1, 3, -3, 1]));
+        assert!(roots.roots.iter().all(|&root| (root - 1.).norm() > 1e-12));
+        assert_distinct_roots(&[(c(1., 0.), 3)], &roots.clustered(), 1e-9);
+    }
+}
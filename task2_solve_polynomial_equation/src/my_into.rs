@@ -0,0 +1,46 @@
+//! Conversions into `Complex64` and vectors of it, used by [`Polynomial`](crate::Polynomial) constructors.
+
+use num::complex::Complex64;
+
+
+pub trait MyInto<T> {
+    fn into_(self) -> T;
+}
+
+impl MyInto<Complex64> for i64 {
+    fn into_(self) -> Complex64 {
+        (self as f64).into()
+    }
+}
+impl MyInto<Complex64> for f64 {
+    fn into_(self) -> Complex64 {
+        self.into()
+    }
+}
+impl MyInto<Complex64> for Complex64 {
+    fn into_(self) -> Complex64 {
+        self
+    }
+}
+
+impl<const N: usize> MyInto<Vec<Complex64>> for [i64; N] {
+    fn into_(self) -> Vec<Complex64> {
+        self.into_iter().map(|x| (x as f64).into()).collect()
+    }
+}
+impl<const N: usize> MyInto<Vec<Complex64>> for [f64; N] {
+    fn into_(self) -> Vec<Complex64> {
+        self.into_iter().map(|x| x.into()).collect()
+    }
+}
+impl MyInto<Vec<Complex64>> for Vec<Complex64> {
+    fn into_(self) -> Vec<Complex64> {
+        self
+    }
+}
+
+impl<const N: usize> MyInto<[Complex64; N]> for [Complex64; N] {
+    fn into_(self) -> [Complex64; N] {
+        self
+    }
+}
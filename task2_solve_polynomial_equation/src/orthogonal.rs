@@ -0,0 +1,160 @@
+//! Classical orthogonal polynomials by their three-term recurrences, and Chebyshev basis.
+
+use num::complex::Complex64;
+
+use crate::polynomial::Polynomial;
+
+
+impl Polynomial {
+    /// Chebyshev polynomial of the first kind: `T_n(cos θ) = cos nθ`,
+    /// `T_0 = 1`, `T_1 = x`, `T_(n+1) = 2x T_n - T_(n-1)`.
+    pub fn chebyshev_t(n: usize) -> Self {
+        recurrence(n, Polynomial::one(), Polynomial::x(), |_, p, p_prev| {
+            &(&Polynomial::new([0, 2]) * p) - p_prev
+        })
+    }
+
+    /// Chebyshev polynomial of the second kind: `U_n(cos θ) = sin (n+1)θ / sin θ`,
+    /// `U_0 = 1`, `U_1 = 2x`, `U_(n+1) = 2x U_n - U_(n-1)`.
+    pub fn chebyshev_u(n: usize) -> Self {
+        recurrence(n, Polynomial::one(), Polynomial::new([0, 2]), |_, p, p_prev| {
+            &(&Polynomial::new([0, 2]) * p) - p_prev
+        })
+    }
+
+    /// Legendre polynomial, orthogonal on `[-1, 1]`: `P_0 = 1`, `P_1 = x`,
+    /// `(n+1) P_(n+1) = (2n+1) x P_n - n P_(n-1)`. Its roots are nodes of Gauss–Legendre quadrature.
+    pub fn legendre(n: usize) -> Self {
+        recurrence(n, Polynomial::one(), Polynomial::x(), |n, p, p_prev| {
+            let n = n as f64;
+            &(&(&Polynomial::new([0., 2. * n + 1.]) * p) - &(&Polynomial::monomial(n) * p_prev))
+                * &Polynomial::monomial(1. / (n + 1.))
+        })
+    }
+
+    /// Physicists' Hermite polynomial, orthogonal with weight `e^(-x²)`: `H_0 = 1`, `H_1 = 2x`,
+    /// `H_(n+1) = 2x H_n - 2n H_(n-1)`.
+    pub fn hermite(n: usize) -> Self {
+        recurrence(n, Polynomial::one(), Polynomial::new([0, 2]), |n, p, p_prev| {
+            &(&Polynomial::new([0, 2]) * p) - &(&Polynomial::monomial(2. * n as f64) * p_prev)
+        })
+    }
+
+    /// Laguerre polynomial, orthogonal with weight `e^(-x)` on `[0, ∞)`: `L_0 = 1`, `L_1 = 1 - x`,
+    /// `(n+1) L_(n+1) = (2n+1 - x) L_n - n L_(n-1)`.
+    pub fn laguerre(n: usize) -> Self {
+        recurrence(n, Polynomial::one(), Polynomial::new([1, -1]), |n, p, p_prev| {
+            let n = n as f64;
+            &(&(&Polynomial::new([2. * n + 1., -1.]) * p) - &(&Polynomial::monomial(n) * p_prev))
+                * &Polynomial::monomial(1. / (n + 1.))
+        })
+    }
+
+    /// Coefficients `c_k` in Chebyshev basis: `p = Σ c_k T_k`.
+    ///
+    /// By Horner's scheme `p = a_0 + x (a_1 + x (…))`, where multiplication by `x` in Chebyshev basis is
+    /// `x T_0 = T_1`, `x T_k = (T_(k+1) + T_(k-1)) / 2`.
+    pub fn to_chebyshev_basis(&self) -> Vec<Complex64> {
+        let mut c = vec![self.get_k_last()];
+        for &a in self.k.iter().rev().skip(1) {
+            let mut xc = vec![Complex64::new(0., 0.); c.len() + 1];
+            for (k, &c_k) in c.iter().enumerate() {
+                if k == 0 {
+                    xc[1] += c_k;
+                } else {
+                    xc[k+1] += c_k / 2.;
+                    xc[k-1] += c_k / 2.;
+                }
+            }
+            xc[0] += a;
+            c = xc;
+        }
+        c
+    }
+
+    /// Inverse of [`to_chebyshev_basis`](Self::to_chebyshev_basis): `Σ c_k T_k`.
+    pub fn from_chebyshev_basis(c: &[Complex64]) -> Self {
+        let (mut t_prev, mut t) = (Polynomial::zero(), Polynomial::one());
+        let mut p = Polynomial::zero();
+        for (k, &c_k) in c.iter().enumerate() {
+            p = &p + &(&Polynomial::monomial(c_k) * &t);
+            let t_next = if k == 0 { Polynomial::x() } else { &(&Polynomial::new([0, 2]) * &t) - &t_prev };
+            (t_prev, t) = (t, t_next);
+        }
+        p
+    }
+}
+
+
+/// `p_n` from `p_0`, `p_1` and `p_(k+1) = next(k, p_k, p_(k-1))`.
+fn recurrence(
+    n: usize,
+    p_0: Polynomial,
+    p_1: Polynomial,
+    next: impl Fn(usize, &Polynomial, &Polynomial) -> Polynomial,
+) -> Polynomial {
+    if n == 0 { return p_0; }
+    let (mut p_prev, mut p) = (p_0, p_1);
+    for k in 1..n {
+        let p_next = next(k, &p, &p_prev);
+        (p_prev, p) = (p, p_next);
+    }
+    p
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn families() {
+        assert_eq!(Polynomial::new([0, -3, 0, 4]), Polynomial::chebyshev_t(3));
+        assert_eq!(Polynomial::new([-1, 0, 4]), Polynomial::chebyshev_u(2));
+        assert_eq!(Polynomial::new([0., -1.5, 0., 2.5]), Polynomial::legendre(3));
+        assert_eq!(Polynomial::new([0, -12, 0, 8]), Polynomial::hermite(3));
+        assert_eq!(Polynomial::new([1., -2., 0.5]), Polynomial::laguerre(2));
+        assert_eq!(Polynomial::one(), Polynomial::legendre(0));
+        for theta in [0.1, 0.7, 2.] {
+            let x = f64::cos(theta);
+            assert!((Polynomial::chebyshev_t(7).eval_at(x).re - f64::cos(7. * theta)).abs() < 1e-13);
+            assert!((Polynomial::chebyshev_u(7).eval_at(x).re - f64::sin(8. * theta) / f64::sin(theta)).abs() < 1e-12);
+        }
+        // P_n(1) = 1:
+        assert!((Polynomial::legendre(10).eval_at(1.).re - 1.).abs() < 1e-14);
+    }
+
+    #[test]
+    fn chebyshev_basis() {
+        // x² = (T_0 + T_2) / 2:
+        let c = [0.5, 0., 0.5].map(Complex64::from);
+        assert_eq!(c.to_vec(), Polynomial::new([0, 0, 1]).to_chebyshev_basis());
+        assert_eq!(Polynomial::new([0, 0, 1]), Polynomial::from_chebyshev_basis(&c));
+        let p = Polynomial::new([7, 2, 7, 2, 1]);
+        let c = p.to_chebyshev_basis();
+        assert!((&Polynomial::from_chebyshev_basis(&c) - &p).norm_inf() < 1e-14);
+        // T_5 is single basis element:
+        let c = Polynomial::chebyshev_t(5).to_chebyshev_basis();
+        assert!(c.iter().enumerate().all(|(k, &c_k)| (c_k - if k == 5 { 1. } else { 0. }).norm() < 1e-14));
+    }
+
+    #[test]
+    fn gauss_legendre_quadrature() {
+        // nodes are roots of P_n, weights are 2 / ((1 - x²) P'_n(x)²), and n nodes integrate degree 2n-1 exactly:
+        let n = 5;
+        let p = Polynomial::legendre(n);
+        let nodes = p.find_all_solutions();
+        assert!(nodes.iter().all(|x| x.im == 0. && x.re.abs() < 1.));
+        let integral: f64 = nodes.iter()
+            .map(|x| {
+                let weight = 2. / ((1. - x.re * x.re) * p.eval_derivative_at(*x).re.powi(2));
+                weight * x.re.powi(8)
+            })
+            .sum();
+        // ∫₋₁¹ x⁸ dx = 2/9:
+        assert!((integral - 2. / 9.).abs() < 1e-14);
+    }
+}
@@ -0,0 +1,282 @@
+//! Parsing of polynomials from strings like `x^4 + 2x^3 + 7x^2 + 2x + 7` or `(1+2i)x^2 - 3`.
+//!
+//! Grammar (whitespace is ignored):
+//! ```text
+//! polynomial  = [sign] term {sign term}
+//! term        = coefficient [["*"] monomial] | monomial
+//! monomial    = "x" [exponent]
+//! exponent    = "^" digits | "^{" digits "}" | superscript digits
+//! coefficient = real ["i"] | "i" | "(" [sign] real ["i"] {sign real ["i"]} ")"
+//! ```
+//! Terms with same power are summed, so output of [`Display`](std::fmt::Display),
+//! [`to_ascii`](Polynomial::to_ascii) and [`to_latex`](Polynomial::to_latex) can be parsed back.
+
+use std::{error::Error, fmt, str::FromStr};
+
+use num::{complex::Complex64, Zero};
+
+use crate::{format::SUPERSCRIPT_DIGITS, polynomial::Polynomial};
+
+
+/// Error of parsing polynomial, `position` is index of char in string.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsePolynomialError {
+    Empty,
+    UnexpectedChar { position: usize, found: char },
+    UnexpectedEnd,
+    InvalidNumber { position: usize, text: String },
+    /// Exponent is greater than [`Polynomial::MAX_PARSED_DEGREE`].
+    DegreeTooLarge { position: usize, text: String },
+}
+
+impl fmt::Display for ParsePolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty polynomial"),
+            Self::UnexpectedChar { position, found } => write!(f, "unexpected '{found}' at position {position}"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of polynomial"),
+            Self::InvalidNumber { position, text } => write!(f, "invalid number \"{text}\" at position {position}"),
+            Self::DegreeTooLarge { position, text } => write!(
+                f, "degree {text}… at position {position} is greater than {}", Polynomial::MAX_PARSED_DEGREE,
+            ),
+        }
+    }
+}
+
+impl Error for ParsePolynomialError {}
+
+
+impl Polynomial {
+    /// Max power of `x` in parsed polynomial, so huge exponent can't exhaust memory.
+    pub const MAX_PARSED_DEGREE: usize = 10_000;
+}
+
+
+impl FromStr for Polynomial {
+    type Err = ParsePolynomialError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            chars: s.chars().enumerate().filter(|(_, c)| !c.is_whitespace()).collect(),
+            i: 0,
+        };
+        if parser.chars.is_empty() { return Err(ParsePolynomialError::Empty); }
+        let mut k: Vec<Complex64> = vec![];
+        let mut is_first = true;
+        while parser.peek().is_some() {
+            let sign = match parser.peek() {
+                Some('+') => { parser.i += 1; 1. }
+                Some('-') => { parser.i += 1; -1. }
+                Some(c) if !is_first => return Err(parser.unexpected(c)),
+                _ => 1.,
+            };
+            is_first = false;
+            let (coef, power) = parser.term()?;
+            if k.len() <= power { k.resize(power + 1, Complex64::zero()); }
+            k[power] += sign * coef;
+        }
+        Ok(Polynomial::new(k))
+    }
+}
+
+
+struct Parser {
+    /// Non-whitespace chars with their positions in original string.
+    chars: Vec<(usize, char)>,
+    i: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.i).map(|&(_, c)| c)
+    }
+
+    fn position(&self) -> usize {
+        self.chars.get(self.i).map_or_else(|| self.chars.last().map_or(0, |&(p, _)| p + 1), |&(p, _)| p)
+    }
+
+    fn unexpected(&self, found: char) -> ParsePolynomialError {
+        ParsePolynomialError::UnexpectedChar { position: self.position(), found }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParsePolynomialError> {
+        match self.peek() {
+            Some(c) if c == expected => { self.i += 1; Ok(()) }
+            Some(c) => Err(self.unexpected(c)),
+            None => Err(ParsePolynomialError::UnexpectedEnd),
+        }
+    }
+
+    /// Returns `(coefficient, power)`.
+    fn term(&mut self) -> Result<(Complex64, usize), ParsePolynomialError> {
+        let coef = match self.peek() {
+            Some('x') => return Ok((Complex64::new(1., 0.), self.monomial()?)),
+            Some('(') => {
+                self.i += 1;
+                let coef = self.complex()?;
+                self.expect(')')?;
+                coef
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' || c == 'i' => self.imaginary_or_real()?,
+            Some(c) => return Err(self.unexpected(c)),
+            None => return Err(ParsePolynomialError::UnexpectedEnd),
+        };
+        match self.peek() {
+            Some('*') => { self.i += 1; Ok((coef, self.monomial()?)) }
+            Some('x') => Ok((coef, self.monomial()?)),
+            _ => Ok((coef, 0)),
+        }
+    }
+
+    /// Sum of real and imaginary numbers with signs, inside of parentheses.
+    fn complex(&mut self) -> Result<Complex64, ParsePolynomialError> {
+        let mut sum = Complex64::zero();
+        let mut is_first = true;
+        while self.peek() != Some(')') {
+            let sign = match self.peek() {
+                Some('+') => { self.i += 1; 1. }
+                Some('-') => { self.i += 1; -1. }
+                Some(c) if !is_first => return Err(self.unexpected(c)),
+                None => return Err(ParsePolynomialError::UnexpectedEnd),
+                _ => 1.,
+            };
+            is_first = false;
+            sum += sign * self.imaginary_or_real()?;
+        }
+        if is_first { return Err(self.unexpected(')')); }
+        Ok(sum)
+    }
+
+    /// `real`, `real i` or `i`.
+    fn imaginary_or_real(&mut self) -> Result<Complex64, ParsePolynomialError> {
+        if self.peek() == Some('i') {
+            self.i += 1;
+            return Ok(Complex64::i());
+        }
+        let x = self.real()?;
+        if self.peek() == Some('i') {
+            self.i += 1;
+            Ok(Complex64::new(0., x))
+        } else {
+            Ok(Complex64::new(x, 0.))
+        }
+    }
+
+    /// Digits with optional fraction and exponent, like `1.5e-3`.
+    fn real(&mut self) -> Result<f64, ParsePolynomialError> {
+        let position = self.position();
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            let is_exponent_sign = (c == '+' || c == '-') && text.ends_with(['e', 'E']);
+            if !(c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || is_exponent_sign) { break; }
+            text.push(c);
+            self.i += 1;
+        }
+        match self.peek() {
+            None if text.is_empty() => Err(ParsePolynomialError::UnexpectedEnd),
+            Some(c) if text.is_empty() => Err(self.unexpected(c)),
+            _ => text.parse().map_err(|_| ParsePolynomialError::InvalidNumber { position, text }),
+        }
+    }
+
+    /// `x` with optional exponent, returns power.
+    fn monomial(&mut self) -> Result<usize, ParsePolynomialError> {
+        self.expect('x')?;
+        match self.peek() {
+            Some('^') => {
+                self.i += 1;
+                let is_braced = self.peek() == Some('{');
+                if is_braced { self.i += 1; }
+                let power = self.digits(|c| c.to_digit(10))?;
+                if is_braced { self.expect('}')?; }
+                Ok(power)
+            }
+            Some(c) if SUPERSCRIPT_DIGITS.contains(&c) => {
+                self.digits(|c| SUPERSCRIPT_DIGITS.iter().position(|&d| d == c).map(|d| d as u32))
+            }
+            _ => Ok(1),
+        }
+    }
+
+    fn digits(&mut self, to_digit: impl Fn(char) -> Option<u32>) -> Result<usize, ParsePolynomialError> {
+        let position = self.position();
+        let mut text = String::new();
+        let mut power: usize = 0;
+        while let Some(d) = self.peek().and_then(&to_digit) {
+            text.push(self.peek().unwrap());
+            power = 10 * power + d as usize;
+            if power > Polynomial::MAX_PARSED_DEGREE {
+                return Err(ParsePolynomialError::DegreeTooLarge { position, text });
+            }
+            self.i += 1;
+        }
+        match self.peek() {
+            None if text.is_empty() => Err(ParsePolynomialError::UnexpectedEnd),
+            Some(c) if text.is_empty() => Err(self.unexpected(c)),
+            _ => Ok(power),
+        }
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(s: &str) -> Polynomial {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Polynomial::new([7, 2, 7, 2, 1]), p("x^4 + 2x^3 + 7x^2 + 2x + 7"));
+        assert_eq!(Polynomial::new(vec![(-3.).into(), 0.0.into(), Complex64::new(1., 2.)]), p("(1+2i)x^2 - 3"));
+        assert_eq!(Polynomial::new([0.5, -1., 0., 1e-3]), p("-x + 1e-3*x^{3} + .5"));
+        assert_eq!(Polynomial::new(vec![Complex64::new(0., 2.), Complex64::new(0., -1.)]), p("2i - i x"));
+        // like terms are summed:
+        assert_eq!(Polynomial::new([0, 3]), p("x + 2 x"));
+        assert_eq!(Polynomial::zero(), p("x - x"));
+        assert_eq!(Polynomial::monomial(Complex64::new(1., -1.)), p("(-i + 1)"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(ParsePolynomialError::Empty), "  ".parse::<Polynomial>());
+        assert_eq!(Err(ParsePolynomialError::UnexpectedEnd), "x^2 +".parse::<Polynomial>());
+        assert_eq!(Err(ParsePolynomialError::UnexpectedChar { position: 4, found: 'y' }), "x + y".parse::<Polynomial>());
+        assert_eq!(Err(ParsePolynomialError::UnexpectedChar { position: 4, found: 'x' }), "2 x x".parse::<Polynomial>());
+        assert_eq!(
+            Err(ParsePolynomialError::InvalidNumber { position: 0, text: "1.2.3".to_string() }),
+            "1.2.3 x".parse::<Polynomial>()
+        );
+        assert_eq!(Err(ParsePolynomialError::UnexpectedChar { position: 1, found: ')' }), "()".parse::<Polynomial>());
+        assert_eq!(
+            Err(ParsePolynomialError::DegreeTooLarge { position: 2, text: "99999".to_string() }),
+            "x^99999999999".parse::<Polynomial>()
+        );
+        assert_eq!(
+            Err(ParsePolynomialError::DegreeTooLarge { position: 3, text: "18446".to_string() }),
+            "x^{18446744073709551615}".parse::<Polynomial>()
+        );
+        assert_eq!(Polynomial::MAX_PARSED_DEGREE, p("x^10000").degree());
+    }
+
+    #[test]
+    fn parse_formatted() {
+        for q in [
+            Polynomial::new([7, 2, 7, 2, 1]),
+            Polynomial::new(vec![1.0.into(), (-2.0).into(), Complex64::new(1., 2.)]),
+            Polynomial::new(vec![Complex64::new(0., -0.25), 0.0.into(), Complex64::new(-1.5, 1e-3)]),
+            Polynomial::monomial(-3.),
+            Polynomial::zero(),
+            &Polynomial::new([1, 1]) * &Polynomial::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2]),
+        ] {
+            assert_eq!(q, p(&q.to_string()));
+            assert_eq!(q, p(&q.to_ascii()));
+            assert_eq!(q, p(&q.to_latex()));
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! A posteriori refinement of roots: polishing by Newton's method and inclusion discs.
+//!
+//! Inclusion discs follow Gershgorin's theorem for Weierstrass corrections, see Bini, Fiorentino
+//! "Design, analysis, and implementation of a multiprecision polynomial rootfinder".
+
+use num::complex::Complex64;
+
+use crate::{
+    polynomial::Polynomial,
+    solvers::{Roots, Solver},
+};
+
+
+impl Solver {
+    /// Max number of Newton's iterations per root in [`polished`](Self::polished).
+    pub const POLISH_ITERS: u32 = 5;
+
+    /// Refines every root by Newton's method on original polynomial: roots found by deflation are roots
+    /// of perturbed polynomials, and roots of other methods keep error of their last iteration.
+    ///
+    /// Step is accepted only if it decreases residual `|p(z)|`, so root can't jump away.
+    pub(crate) fn polished(&self, p: &Polynomial, roots: Vec<Complex64>) -> Vec<Complex64> {
+        roots.into_iter()
+            .map(|mut z| {
+                let mut p_z = p.eval_compensated_at(z);
+                for _ in 0..Self::POLISH_ITERS {
+                    if p_z.norm() == 0. { break; }
+                    let z_new = z - p_z / p.eval_derivative_at(z);
+                    let p_z_new = p.eval_compensated_at(z_new);
+                    if !p_z_new.is_finite() || p_z_new.norm() >= p_z.norm() { break; }
+                    (z, p_z) = (z_new, p_z_new);
+                }
+                z
+            })
+            .collect()
+    }
+}
+
+
+impl Roots {
+    /// Disc `i` doesn't intersect others, so it contains exactly one root, which is simple.
+    pub fn is_isolated(&self, i: usize) -> bool {
+        (0..self.roots.len())
+            .filter(|&j| j != i)
+            .all(|j| (self.roots[i] - self.roots[j]).norm() > self.inclusion_radii[i] + self.inclusion_radii[j])
+    }
+}
+
+
+/// Radii `n |W_i|` of inclusion discs, where `W_i = p(z_i) / (a_n ∏_(j≠i) (z_i - z_j))` is Weierstrass correction:
+/// union of discs `|x - z_i| <= n |W_i|` contains all roots, and every its connected component of `m` discs
+/// contains exactly `m` roots (with multiplicity).
+///
+/// `|p(z_i)|` is increased by bound of rounding error of compensated Horner's scheme `u |p(z)| + 8 γ_2n² p̃(|z|)`,
+/// where `p̃` has coefficients `|a_k|`, so discs are rigorous up to rounding of radii themselves.
+pub(crate) fn inclusion_radii(p: &Polynomial, roots: &[Complex64]) -> Vec<f64> {
+    let n = roots.len();
+    let u = f64::EPSILON / 2.;
+    let gamma = |k: usize| k as f64 * u / (1. - k as f64 * u);
+    roots.iter().enumerate()
+        .map(|(i, &z)| {
+            let p_z = p.eval_compensated_at(z).norm();
+            let p_abs_z = p.k.iter().rev().fold(0., |acc, k| acc * z.norm() + k.norm());
+            let p_z_bound = (1. + u) * p_z + 8. * gamma(2 * n).powi(2) * p_abs_z;
+            let denominator: f64 = roots.iter().enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, z_j)| (z - z_j).norm())
+                .product();
+            (1. + gamma(2 * n)) * n as f64 * p_z_bound / (p.get_k_last().norm() * denominator)
+        })
+        .collect()
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::Method;
+
+    #[test]
+    fn polished() {
+        // (x - 2)(x - 3)(x - 5)(x - 7) with perturbed roots:
+        let p = Polynomial::new([210, -247, 101, -17, 1]);
+        let roots = Solver::default().polished(&p, [2. + 1e-6, 3. - 1e-5, 5.1, 7. + 1e-7].map(Complex64::from).to_vec());
+        for (root, expected) in roots.into_iter().zip([2., 3., 5., 7.]) {
+            assert!((root - expected).norm() < 1e-14, "{root}");
+        }
+        // deflation isn't exact, but polishing fixes it:
+        let p = Polynomial::new([-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]); // x¹⁶ - 1
+        let unpolished = Solver::new(Method::NewtonDeflation).with_polishing(false).solve(&p);
+        let polished = Solver::new(Method::NewtonDeflation).solve(&p);
+        let max_residual = |roots: &Roots| roots.roots.iter().map(|&z| p.eval_compensated_at(z).norm()).fold(0., f64::max);
+        assert!(max_residual(&polished) <= max_residual(&unpolished));
+    }
+
+    #[test]
+    fn inclusion_discs() {
+        let p = Polynomial::new([7, 2, 7, 2, 1]);
+        let roots = Solver::default().solve(&p);
+        let exact = [
+            Complex64::new(-1.0356447261076875, -2.2144580256427964),
+            Complex64::new(-1.0356447261076875, 2.2144580256427964),
+            Complex64::new(0.0356447261076875, -1.0816682476065231),
+            Complex64::new(0.0356447261076875, 1.0816682476065231),
+        ];
+        for (i, exact) in exact.into_iter().enumerate() {
+            assert!(roots.is_isolated(i));
+            assert!(roots.inclusion_radii[i] < 1e-14);
+            assert!((roots.roots[i] - exact).norm() <= roots.inclusion_radii[i] + 1e-15);
+        }
+        // perturbed roots of (x - 1)(x - 2): discs must contain exact roots:
+        let p = Polynomial::new([2, -3, 1]);
+        let zs = [Complex64::new(1.01, 0.01), Complex64::new(1.98, 0.)];
+        let radii = inclusion_radii(&p, &zs);
+        assert!((zs[0] - 1.).norm() <= radii[0] && (zs[1] - 2.).norm() <= radii[1]);
+        // discs of double root can't be separated:
+        let roots = Solver::default().solve(&Polynomial::new([1, 2, 1]));
+        assert!(!roots.is_isolated(0) && !roots.is_isolated(1));
+    }
+}
@@ -0,0 +1,300 @@
+//! Polynomial with complex coefficients, most of operations are generic over [`Scalar`] coefficients.
+
+use std::fmt;
+
+use num::{
+    complex::Complex64,
+    One,
+    Zero,
+};
+
+use crate::{
+    format::superscript,
+    my_into::MyInto,
+    scalar::Scalar,
+    solvers::{Method, Roots, Solver},
+};
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial<T = Complex64> {
+    /// Coefficients: `a0 + a1*x + a2*x² + …`, last one is non-zero, except for zero polynomial, which is `[0]`.
+    pub(crate) k: Vec<T>,
+}
+
+impl<T: Scalar> Polynomial<T> {
+    /// Leading zero coefficients are removed.
+    pub fn from_coefs(k: Vec<T>) -> Self {
+        let mut k = k;
+        while k.last().is_some_and(|k| k.is_zero()) {
+            k.pop();
+        }
+        if k.is_empty() {
+            k.push(T::zero());
+        }
+        Self { k }
+    }
+
+    /// Converts coefficients by `f`.
+    pub fn map<U: Scalar>(&self, f: impl Fn(&T) -> U) -> Polynomial<U> {
+        Polynomial::from_coefs(self.k.iter().map(f).collect())
+    }
+
+    pub fn get_size(&self) -> usize { self.k.len() }
+
+    /// Degree of polynomial, for constants (including zero) it's `0`.
+    pub fn degree(&self) -> usize { self.k.len() - 1 }
+
+    pub fn is_zero(&self) -> bool { self.k.iter().all(|k| k.is_zero()) }
+
+    pub fn get_coefs(&self) -> Vec<T> { self.k.clone() }
+    pub fn get_k_at(&self, i: usize) -> T { self.k[i].clone() }
+    pub fn get_k_last(&self) -> T { self.k.last().unwrap().clone() }
+
+    /// Evaluates by Horner's scheme in arithmetic of `T`.
+    pub fn eval(&self, x: &T) -> T {
+        self.k.iter().rev().fold(T::zero(), |r, k| r * x.clone() + k.clone())
+    }
+}
+
+impl Polynomial {
+    pub fn new(k: impl MyInto<Vec<Complex64>>) -> Self {
+        Self { k: k.into_() }.trimmed()
+    }
+
+    /// returns: `C0 + C1 x`
+    pub fn binomial(k: impl MyInto<[Complex64; 2]>) -> Self {
+        let k: [Complex64; 2] = k.into_();
+        Self::new(vec![k[0], k[1]])
+    }
+
+    /// returns: `C + 1x`
+    pub fn binomial_normalized(c: impl MyInto<Complex64>) -> Self {
+        Self::binomial([c.into_(), Complex64::one()])
+    }
+
+    /// returns: `C`
+    pub fn monomial(c: impl Into<Complex64>) -> Self {
+        Self { k: vec![c.into()] }
+    }
+
+    pub fn zero() -> Self { Polynomial::monomial(Complex64::zero()) }
+    pub fn one() -> Self { Polynomial::monomial(Complex64::one()) }
+
+    /// returns: `x`
+    pub fn x() -> Self { Polynomial::binomial_normalized(0) }
+
+    /// All coefficients are real.
+    pub fn is_real(&self) -> bool { self.k.iter().all(|k| k.im == 0.) }
+
+    pub fn is_binomial_with_norm_k(&self) -> bool {
+        self.get_size() == 2 && self.k[1] == 1.0.into()
+    }
+
+    /// Removes leading (highest degree) zero coefficients.
+    pub fn trimmed(self) -> Self {
+        self.trimmed_by(0.)
+    }
+
+    /// Removes leading (highest degree) coefficients, which are `<= tolerance` by absolute value,
+    /// so if all of them are such, it becomes zero polynomial.
+    pub fn trimmed_by(mut self, tolerance: f64) -> Self {
+        while self.k.last().is_some_and(|k| k.norm() <= tolerance) {
+            self.k.pop();
+        }
+        if self.k.is_empty() {
+            self.k.push(Complex64::zero());
+        }
+        self
+    }
+
+    const TOLERANCE: f64 = 1e-6;
+
+    /// Finds all roots by default [`Solver`] (Aberth–Ehrlich), see [`Roots::roots`] for order.
+    /// Multiple roots are found as close simple ones, see [`find_distinct_solutions`](Self::find_distinct_solutions).
+    pub fn find_all_solutions(&self) -> Vec<Complex64> {
+        Solver::default().solve(self).roots
+    }
+
+    /// Finds all roots by given `method`, with error bounds.
+    pub fn find_all_solutions_by(&self, method: Method) -> Roots {
+        Solver::new(method).solve(self)
+    }
+
+    /// Finds root by Newton's method from `start_point`, `None` if derivative vanishes at some iterate
+    /// or it doesn't converge in 100 iterations.
+    pub fn find_one_solution(&self, start_point: impl Into<Complex64>) -> Option<Complex64> {
+        let mut x: Complex64 = start_point.into();
+        for _i in 0..100 {
+            let (p, d) = self.eval_with_derivative_at(x);
+            if p.is_zero() { return Some(x); }
+            let dx = p / d;
+            if !dx.is_finite() { return None; }
+            x -= dx;
+            if dx.norm_sqr() < Self::TOLERANCE { return Some(x); }
+        }
+        None
+    }
+
+    /// Evaluates by Horner's scheme.
+    pub fn eval_at(&self, x: impl Into<Complex64>) -> Complex64 {
+        let x: Complex64 = x.into();
+        self.k.iter().rev().fold(Complex64::zero(), |r, k| r * x + k)
+    }
+
+    /// Evaluates exact derivative by Horner's scheme.
+    pub fn eval_derivative_at(&self, x: impl Into<Complex64>) -> Complex64 {
+        self.eval_with_derivative_at(x).1
+    }
+
+    /// Evaluates `(p(x), p'(x))` simultaneously by Horner's scheme.
+    pub fn eval_with_derivative_at(&self, x: impl Into<Complex64>) -> (Complex64, Complex64) {
+        let x: Complex64 = x.into();
+        let mut p = Complex64::zero();
+        let mut d = Complex64::zero();
+        for k in self.k.iter().rev() {
+            d = d * x + p;
+            p = p * x + k;
+        }
+        (p, d)
+    }
+
+    /// Divides by `rhs`, returns `None` if remainder isn't zero (up to `TOLERANCE`).
+    ///
+    /// See [`div_rem`](Self::div_rem) for example.
+    pub fn div_by(&self, rhs: Polynomial) -> Option<Polynomial> {
+        let (quotient, remainder) = self.div_rem(&rhs);
+        let remainder: f64 = remainder.k.iter().map(|k| k.norm_sqr()).fold(0., f64::max);
+        if remainder > Self::TOLERANCE { return None; }
+        Some(quotient)
+    }
+}
+
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::<String>::with_capacity(self.get_size());
+        for (i, k) in self.k.iter().enumerate() {
+            let k = if k.im == 0. { k.re.to_string() } else { format!("({k})") };
+            parts.push(
+                match i {
+                    0 => k,
+                    1 => format!("{k} x"),
+                    i => format!("{k} x{}", superscript(i)),
+                }
+            );
+        }
+        let s = parts.into_iter().reduce(
+            |acc, el| {
+                acc + &(if let Some(elm) = el.strip_prefix('-') {
+                    format!(" - {elm}")
+                } else {
+                    format!(" + {el}")
+                })
+            }
+        ).unwrap();
+        write!(f, "{s}")
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn polynomial_eval_at() {
+        let p = Polynomial::new([5.2, 6.1, 9.0, 0.42]);
+        assert_eq!(Complex64::new(5.2, 0.), p.eval_at(0.));
+        assert_eq!(Complex64::new(34.2479225, 0.), Polynomial::new([5.2, 6.1, 9.0, 0.42]).eval_at(1.45));
+    }
+
+    #[test]
+    fn polynomial_div() {
+        assert_eq!(
+            Some(Polynomial::one()),
+            Polynomial::binomial_normalized(2.).div_by(Polynomial::binomial_normalized(2.))
+        );
+
+        assert_eq!(
+            Some(Polynomial::binomial_normalized(3.)),
+            Polynomial::new([21, 10, 1]).div_by(Polynomial::binomial_normalized(7.))
+        );
+
+        assert_eq!(
+            Some(Polynomial::binomial_normalized(1.)),
+            Polynomial::new([1, 2, 1]).div_by(Polynomial::binomial_normalized(1.))
+        );
+        assert_eq!(
+            None,
+            Polynomial::new([1, 2, 1]).div_by(Polynomial::binomial_normalized(-1.))
+        );
+
+        assert_eq!(
+            Some(Polynomial::binomial_normalized(-1.)),
+            Polynomial::new([1, -2, 1]).div_by(Polynomial::binomial_normalized(-1.))
+        );
+    }
+
+    #[test]
+    fn trimmed() {
+        assert_eq!(vec![Complex64::new(1., 0.), Complex64::new(2., 0.)], Polynomial::new([1, 2, 0, 0]).k);
+        assert_eq!(Polynomial::zero(), Polynomial::new([0, 0]));
+        assert_eq!(2, Polynomial::new([1., 2., 1e-20]).trimmed_by(1e-15).get_size());
+        assert_eq!(Polynomial::zero(), Polynomial::new([1e-20, 1e-20]).trimmed_by(1e-15));
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!("7 + 2 x + 7 x² + 2 x³ + 1 x⁴", Polynomial::new([7, 2, 7, 2, 1]).to_string());
+        assert_eq!("1 - 2 x + (1+2i) x²", Polynomial::new(vec![1.0.into(), (-2.0).into(), Complex64::new(1., 2.)]).to_string());
+        assert!(Polynomial::new([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]).to_string().ends_with(" + 0 x⁹ + 1 x¹⁰"));
+    }
+
+    #[test]
+    fn find_one_solution() {
+        let p = Polynomial::new([1, 0, 1]);
+        let root = p.find_one_solution(Complex64::new(1., 1.)).unwrap();
+        assert!((root - Complex64::i()).norm() < 1e-3, "{root}");
+        // p'(0) = 0:
+        assert_eq!(None, p.find_one_solution(0.));
+        // iterates stay real, so they never converge to ±i:
+        assert_eq!(None, p.find_one_solution(0.5));
+        assert_eq!(Some(Complex64::new(2., 0.)), Polynomial::new([-4, 0, 1]).find_one_solution(2.));
+    }
+
+    #[test]
+    fn polynomial_find_all_solutions() {
+        assert_eq!(
+            [
+                -1,
+            ].into_(),
+            Polynomial::binomial_normalized(1).find_all_solutions()
+        );
+        assert_eq!(
+            [
+                1,
+            ].into_(),
+            Polynomial::binomial_normalized(-1).find_all_solutions()
+        );
+        assert_eq!(
+            [
+                2,
+                3,
+                5,
+                7,
+            ].into_(),
+            Polynomial::new([210, -247, 101, -17, 1]).find_all_solutions()
+        );
+        // double root is found as two close ones, see `find_distinct_solutions`:
+        let roots = Polynomial::new([1, 2, 1]).find_all_solutions();
+        assert_eq!(2, roots.len());
+        assert!(roots.iter().all(|&root| (root + 1.).norm() < 1e-7), "{roots:?}");
+        // close roots aren't merged:
+        let p = &Polynomial::new([-1, 1]) * &Polynomial::new([-1. - 1e-5, 1.]);
+        let roots = p.find_all_solutions();
+        assert!((roots[0] - 1.).norm() < 1e-10 && (roots[1] - (1. + 1e-5)).norm() < 1e-10, "{roots:?}");
+    }
+}
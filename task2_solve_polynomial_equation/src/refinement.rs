@@ -0,0 +1,106 @@
+//! Roots in precision of coefficients' type, like [`DoubleDouble`](crate::DoubleDouble).
+//!
+//! Ill-conditioned roots (like of Wilkinson's polynomial `(x - 1)(x - 2)…(x - 20)`) can't be found accurately
+//! in `f64`, because rounding errors of coefficients and evaluation move them a lot.
+
+use num::{complex::Complex64, Complex, Zero};
+
+use crate::{polynomial::Polynomial, scalar::RealScalar, solvers::Solver};
+
+
+impl<T: RealScalar> Polynomial<Complex<T>> {
+    /// Finds all roots: approximations are found in `f64` by default [`Solver`],
+    /// and then are refined by Aberth–Ehrlich iterations in arithmetic of `T`,
+    /// until every correction is `<= tolerance * max(1, |z|)`. Returns `(roots, is_converged)`.
+    pub fn find_all_solutions_refined(&self, tolerance: T, max_iters: u32) -> (Vec<Complex<T>>, bool) {
+        let to_f64 = |x: &T| x.to_f64().unwrap_or(f64::NAN);
+        let p_f64 = self.map(|k| Complex64::new(to_f64(&k.re), to_f64(&k.im)));
+        let from_f64 = |x: f64| T::from_f64(x).unwrap();
+        let mut zs: Vec<Complex<T>> = Solver::default().solve(&p_f64).roots.into_iter()
+            .map(|z| Complex::new(from_f64(z.re), from_f64(z.im)))
+            .collect();
+        let derivative = self.derivative();
+        let tolerance_sqr = tolerance.clone() * tolerance;
+        for _ in 0..max_iters {
+            let mut is_converged = true;
+            for i in 0..zs.len() {
+                let dz = aberth_ehrlich_correction(self, &derivative, &zs, i);
+                zs[i] = zs[i].clone() - dz.clone();
+                let scale = zs[i].norm_sqr();
+                let scale = if scale > T::one() { scale } else { T::one() };
+                is_converged &= dz.norm_sqr() <= tolerance_sqr.clone() * scale;
+            }
+            if is_converged { return (zs, true); }
+        }
+        (zs, false)
+    }
+}
+
+
+/// Same as [`Method::AberthEhrlich`](crate::Method::AberthEhrlich) correction, but in arithmetic of `T`.
+fn aberth_ehrlich_correction<T: RealScalar>(
+    p: &Polynomial<Complex<T>>,
+    derivative: &Polynomial<Complex<T>>,
+    zs: &[Complex<T>],
+    i: usize,
+) -> Complex<T> {
+    let p_z = p.eval(&zs[i]);
+    if p_z.is_zero() { return Complex::zero(); }
+    let sum: Complex<T> = zs.iter().enumerate()
+        .filter(|&(j, _)| j != i)
+        .map(|(_, z_j)| Complex::new(T::one(), T::zero()) / (zs[i].clone() - z_j.clone()))
+        .fold(Complex::zero(), |acc, x| acc + x);
+    let newton = derivative.eval(&zs[i]) / p_z;
+    Complex::new(T::one(), T::zero()) / (newton - sum)
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use num::{BigInt, BigRational, FromPrimitive, ToPrimitive};
+
+    use super::*;
+    use crate::DoubleDouble;
+
+    /// `(x - 1)(x - 2)…(x - 20)` with exact coefficients.
+    fn wilkinson() -> Polynomial<BigRational> {
+        (1..=20).fold(Polynomial::from_coefs(vec![BigRational::from_integer(1.into())]), |p, k| {
+            &p * &Polynomial::from_coefs(vec![BigRational::from_integer(BigInt::from(-k)), BigRational::from_integer(1.into())])
+        })
+    }
+
+    #[test]
+    fn wilkinson_polynomial() {
+        let p = wilkinson();
+        // coefficients are exact, `20!` and so on:
+        assert_eq!(BigRational::from_integer(BigInt::from(2432902008176640000u64)), p.get_k_at(0));
+        for k in 1..=20 {
+            assert!(p.eval(&BigRational::from_integer(k.into())).is_zero());
+        }
+
+        // in `f64` coefficients aren't exact, so roots are far from integers:
+        let p_f64 = p.map(|k| Complex64::new(k.to_f64().unwrap(), 0.));
+        let roots = Solver::default().solve(&p_f64).roots;
+        let max_error_f64 = roots.iter().zip(1..=20)
+            .map(|(z, k)| (z - k as f64).norm())
+            .fold(0., f64::max);
+        assert!(max_error_f64 > 1e-4, "{max_error_f64}");
+
+        // in double-double coefficients are exact, and errors are limited by rounding errors of evaluation:
+        let p_dd = p.map(|k| {
+            let k = DoubleDouble::from_i128(k.to_integer().to_i128().unwrap()).unwrap();
+            Complex::new(k, DoubleDouble::zero())
+        });
+        let (roots, is_converged) = p_dd.find_all_solutions_refined(DoubleDouble::from(1e-17), 100);
+        assert!(is_converged);
+        let mut roots = roots;
+        roots.sort_by(|a, b| a.re.partial_cmp(&b.re).unwrap());
+        for (z, k) in roots.iter().zip(1..=20) {
+            let error = (z - Complex::new(DoubleDouble::from(k as f64), DoubleDouble::zero())).norm_sqr();
+            assert!(error.hi().sqrt() < 1e-18, "{k}: {}{:+}i", z.re, z.im);
+        }
+    }
+}
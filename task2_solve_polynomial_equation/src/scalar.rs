@@ -0,0 +1,18 @@
+//! Numeric types, which can be coefficients of [`Polynomial`](crate::Polynomial).
+
+use std::{fmt::Debug, ops::Neg};
+
+use num::{FromPrimitive, Num, ToPrimitive};
+
+
+/// Field (or ring, if division isn't exact) of coefficients: `f32`, `f64`, [`Complex`](num::Complex) of them,
+/// [`BigRational`](num::BigRational) for exact arithmetic, [`DoubleDouble`](crate::DoubleDouble) and so on.
+pub trait Scalar: Num + Clone + Neg<Output = Self> + Debug {}
+
+impl<T: Num + Clone + Neg<Output = T> + Debug> Scalar for T {}
+
+
+/// Ordered real numbers, which can be converted from and to `f64`.
+pub trait RealScalar: Scalar + PartialOrd + FromPrimitive + ToPrimitive {}
+
+impl<T: Scalar + PartialOrd + FromPrimitive + ToPrimitive> RealScalar for T {}
@@ -0,0 +1,303 @@
+//! Solvers, which find all roots of polynomial.
+
+use std::f64::consts::TAU;
+
+use num::{complex::Complex64, Zero};
+
+use crate::{companion::eigenvalues_of_hessenberg, polishing::inclusion_radii, polynomial::Polynomial};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Newton's method finds one root, which is then deflated (`p = p / (x - root)`), and so on.
+    ///
+    /// Later roots are roots of perturbed polynomial, so errors accumulate.
+    NewtonDeflation,
+    /// Weierstrass–Durand–Kerner: all roots are refined simultaneously, convergence is quadratic.
+    DurandKerner,
+    /// Aberth–Ehrlich: all roots are refined simultaneously, convergence is cubic.
+    AberthEhrlich,
+    /// Eigenvalues of [companion matrix](Polynomial::companion_matrix) by shifted QR algorithm.
+    CompanionMatrix,
+}
+
+
+/// All roots of polynomial.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Roots {
+    /// Sorted by real part, then by imaginary part. For real polynomials almost real roots are made real.
+    pub roots: Vec<Complex64>,
+    /// For every root `z`, disc `|x - z| <= n |p(z) / p'(z)|` contains at least one root of polynomial of degree `n`.
+    pub error_bounds: Vec<f64>,
+    /// Radii of [inclusion discs](crate::polishing), [isolated](Self::is_isolated) disc contains exactly one root.
+    pub inclusion_radii: Vec<f64>,
+    pub iters: u32,
+    pub is_converged: bool,
+}
+
+impl Roots {
+    fn new(p: &Polynomial, roots: Vec<Complex64>, iters: u32, is_converged: bool) -> Self {
+        let error_bound = |z: Complex64| {
+            let (p_z, d_z) = p.eval_with_derivative_at(z);
+            if p_z.is_zero() { 0. } else { p.degree() as f64 * (p_z / d_z).norm() }
+        };
+        let mut roots_and_bounds: Vec<(Complex64, f64)> = roots.into_iter()
+            .map(|z| {
+                let error_bound_z = error_bound(z);
+                if !p.is_real() || z.im.abs() > error_bound_z { return (z, error_bound_z); }
+                // near multiple root `p'(z)` is tiny, so error bound can be large even for non-real root:
+                let z_re = Complex64::from(z.re);
+                let error_bound_z_re = error_bound(z_re);
+                if error_bound_z_re <= error_bound_z { (z_re, error_bound_z_re) } else { (z, error_bound_z) }
+            })
+            .collect();
+        roots_and_bounds.sort_by(|(a, _), (b, _)| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        let (roots, error_bounds): (Vec<Complex64>, Vec<f64>) = roots_and_bounds.into_iter().unzip();
+        let inclusion_radii = inclusion_radii(p, &roots);
+        Self { roots, error_bounds, inclusion_radii, iters, is_converged }
+    }
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solver {
+    pub method: Method,
+    /// Iterations stop when every correction is `< tolerance * max(1, |z|)`.
+    pub tolerance: f64,
+    pub max_iters: u32,
+    /// Roots are [polished](Self::polished) by Newton's method on original polynomial.
+    pub polish: bool,
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new(Method::AberthEhrlich)
+    }
+}
+
+impl Solver {
+    pub const TOLERANCE_DEFAULT: f64 = 1e-14;
+    pub const MAX_ITERS_DEFAULT: u32 = 1000;
+
+    pub fn new(method: Method) -> Self {
+        Self {
+            method,
+            tolerance: Self::TOLERANCE_DEFAULT,
+            max_iters: Self::MAX_ITERS_DEFAULT,
+            polish: true,
+        }
+    }
+
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        Self { tolerance, ..self }
+    }
+
+    pub fn with_max_iters(self, max_iters: u32) -> Self {
+        Self { max_iters, ..self }
+    }
+
+    pub fn with_polishing(self, polish: bool) -> Self {
+        Self { polish, ..self }
+    }
+
+    pub fn solve(&self, p: &Polynomial) -> Roots {
+        let (roots, iters, is_converged) = match p.degree() {
+            0 => (vec![], 0, true),
+            1 => (vec![-p.k[0] / p.k[1]], 0, true),
+            _ => match self.method {
+                Method::NewtonDeflation => self.solve_by_newton_deflation(p),
+                Method::DurandKerner    => self.solve_by_simultaneous_iterations(p, durand_kerner_correction),
+                Method::AberthEhrlich   => self.solve_by_simultaneous_iterations(p, aberth_ehrlich_correction),
+                Method::CompanionMatrix => eigenvalues_of_hessenberg(p.companion_matrix(), self.max_iters),
+            }
+        };
+        let roots = if self.polish { self.polished(p, roots) } else { roots };
+        Roots::new(p, roots, iters, is_converged)
+    }
+
+    /// Every Newton's iteration starts on circle `|z| = roots_lower_bound`, so roots are found mostly from
+    /// smallest by absolute value, which makes deflation stable. Start isn't real, because for polynomial
+    /// with real coefficients Newton's method can't leave real axis.
+    fn solve_by_newton_deflation(&self, p: &Polynomial) -> (Vec<Complex64>, u32, bool) {
+        let mut p: Polynomial = p.clone();
+        let mut roots = vec![];
+        let mut iters = 0;
+        let mut is_converged = true;
+        while p.degree() > 0 {
+            let start = Complex64::from_polar(p.roots_lower_bound(), 1.);
+            let (root, root_iters, is_root_converged) = self.find_one_root_by_newton(&p, start);
+            roots.push(root);
+            iters += root_iters;
+            is_converged &= is_root_converged;
+            p = p.div_rem(&Polynomial::binomial_normalized(-root)).0;
+        }
+        (roots, iters, is_converged)
+    }
+
+    /// Returns `(root, iters, is_converged)`, `p` is evaluated by compensated Horner's scheme.
+    pub(crate) fn find_one_root_by_newton(&self, p: &Polynomial, z: Complex64) -> (Complex64, u32, bool) {
+        let mut z = z;
+        for iter in 1..=self.max_iters {
+            let p_z = p.eval_compensated_at(z);
+            if p_z.is_zero() { return (z, iter, true); }
+            let dz = p_z / p.eval_derivative_at(z);
+            if !dz.is_finite() { return (z, iter, false); }
+            z -= dz;
+            if dz.norm() < self.tolerance * z.norm().max(1.) { return (z, iter, true); }
+        }
+        (z, self.max_iters, false)
+    }
+
+    /// `correction(p, zs, i)` returns correction for `zs[i]`, corrections are applied in place (Gauss–Seidel style).
+    fn solve_by_simultaneous_iterations(
+        &self,
+        p: &Polynomial,
+        correction: fn(&Polynomial, &[Complex64], usize) -> Complex64,
+    ) -> (Vec<Complex64>, u32, bool) {
+        let mut zs = initial_guesses(p);
+        for iter in 1..=self.max_iters {
+            let mut is_converged = true;
+            for i in 0..zs.len() {
+                let dz = correction(p, &zs, i);
+                if !dz.is_finite() {
+                    is_converged = false;
+                    continue;
+                }
+                zs[i] -= dz;
+                is_converged &= dz.norm() < self.tolerance * zs[i].norm().max(1.);
+            }
+            if is_converged { return (zs, iter, true); }
+        }
+        (zs, self.max_iters, false)
+    }
+}
+
+
+/// Points on circle around centroid of roots `-a_(n-1) / (n a_n)` with radius `|p(centroid) / a_n|^(1/n)`
+/// (geometric mean of distances to roots), rotated so they aren't symmetric with respect to real axis.
+/// If radius is zero, then [Fujiwara bound](Polynomial::fujiwara_bound) is used instead.
+fn initial_guesses(p: &Polynomial) -> Vec<Complex64> {
+    let n = p.degree();
+    let k_last = p.get_k_last();
+    let center = -p.k[n-1] / (k_last * n as f64);
+    let radius = (p.eval_at(center) / k_last).norm().powf(1. / n as f64);
+    let radius = if radius > 0. && radius.is_finite() { radius } else { p.fujiwara_bound() };
+    let radius = if radius > 0. && radius.is_finite() { radius } else { 1. };
+    (0..n)
+        .map(|i| center + Complex64::from_polar(radius, TAU * i as f64 / n as f64 + 0.4))
+        .collect()
+}
+
+
+/// `p(z_i) / (a_n ∏_(j≠i) (z_i - z_j))`, `p` is evaluated by compensated Horner's scheme.
+fn durand_kerner_correction(p: &Polynomial, zs: &[Complex64], i: usize) -> Complex64 {
+    let denominator: Complex64 = zs.iter().enumerate()
+        .filter(|&(j, _)| j != i)
+        .map(|(_, z_j)| zs[i] - z_j)
+        .product();
+    p.eval_compensated_at(zs[i]) / (p.get_k_last() * denominator)
+}
+
+
+/// `N_i / (1 - N_i Σ_(j≠i) 1 / (z_i - z_j))`, where `N_i = p(z_i) / p'(z_i)` is Newton's correction,
+/// `p` is evaluated by compensated Horner's scheme.
+fn aberth_ehrlich_correction(p: &Polynomial, zs: &[Complex64], i: usize) -> Complex64 {
+    let p_z = p.eval_compensated_at(zs[i]);
+    let d_z = p.eval_derivative_at(zs[i]);
+    if p_z.is_zero() { return Complex64::zero(); }
+    let sum: Complex64 = zs.iter().enumerate()
+        .filter(|&(j, _)| j != i)
+        .map(|(_, z_j)| 1. / (zs[i] - z_j))
+        .sum();
+    1. / (d_z / p_z - sum)
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (x - 2)(x - 3)(x - 5)(x - 7)
+    fn p_2357() -> Polynomial {
+        Polynomial::new([210, -247, 101, -17, 1])
+    }
+
+    fn assert_roots(expected: &[Complex64], roots: &Roots, tolerance: f64) {
+        assert!(roots.is_converged);
+        assert_eq!(expected.len(), roots.roots.len());
+        for expected in expected {
+            // order of roots with almost equal real parts depends on rounding, so nearest root is taken:
+            let (root, error_bound) = roots.roots.iter().zip(&roots.error_bounds)
+                .min_by(|(a, _), (b, _)| (*a - expected).norm().total_cmp(&(*b - expected).norm()))
+                .unwrap();
+            assert!((expected - root).norm() < tolerance, "expected: {expected}, got: {root}");
+            // error bound must be honest:
+            assert!((expected - root).norm() <= error_bound + 1e-15);
+        }
+    }
+
+    #[test]
+    fn all_methods() {
+        let expected = [2., 3., 5., 7.].map(Complex64::from);
+        let i = Complex64::i();
+        // roots of 7 + 2x + 7x² + 2x³ + x⁴ aren't nice, so they are checked by residual:
+        let p_task = Polynomial::new([7, 2, 7, 2, 1]);
+        // (x - i)(x + i)(x - 1) = x³ - x² + x - 1:
+        let expected_complex = [-i, i, 1.0.into()];
+        let p_complex = Polynomial::new([-1, 1, -1, 1]);
+        for method in [Method::NewtonDeflation, Method::DurandKerner, Method::AberthEhrlich, Method::CompanionMatrix] {
+            let solver = Solver::new(method);
+            assert_roots(&expected, &solver.solve(&p_2357()), 1e-9);
+            assert_roots(&expected_complex, &solver.solve(&p_complex), 1e-9);
+            let roots = solver.solve(&p_task);
+            assert!(roots.is_converged, "{method:?}");
+            assert_eq!(4, roots.roots.len());
+            for root in roots.roots {
+                assert!(p_task.eval_at(root).norm() < 1e-9, "{method:?}: {root}");
+            }
+        }
+    }
+
+    #[test]
+    fn complex_coefficients() {
+        // (x - (1 + 2i))(x + 3i):
+        let (r1, r2) = (Complex64::new(1., 2.), Complex64::new(0., -3.));
+        let p = Polynomial::new(vec![r1 * r2, -(r1 + r2), 1.0.into()]);
+        for method in [Method::DurandKerner, Method::AberthEhrlich, Method::CompanionMatrix] {
+            assert_roots(&[r2, r1], &Solver::new(method).solve(&p), 1e-12);
+        }
+    }
+
+    #[test]
+    fn aberth_is_faster_than_durand_kerner() {
+        let p = Polynomial::new([-1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]); // x¹² - 1
+        let durand_kerner = Solver::new(Method::DurandKerner).solve(&p);
+        let aberth_ehrlich = Solver::new(Method::AberthEhrlich).solve(&p);
+        assert!(durand_kerner.is_converged && aberth_ehrlich.is_converged);
+        assert!(aberth_ehrlich.iters < durand_kerner.iters);
+    }
+
+    #[test]
+    fn multiple_non_real_roots_stay_non_real() {
+        // (x² + 1)² (x - 3):
+        let roots = Solver::default().solve(&Polynomial::new([-3, 1, -6, 2, -3, 1]));
+        assert!(roots.is_converged);
+        for (expected, multiplicity) in [(-Complex64::i(), 2), (Complex64::i(), 2), (3.0.into(), 1)] {
+            let count = roots.roots.iter().filter(|&&root| (root - expected).norm() < 1e-7).count();
+            assert_eq!(multiplicity, count, "{:?}", roots.roots);
+        }
+    }
+
+    #[test]
+    fn not_finite_correction_is_not_converged() {
+        fn correction(_p: &Polynomial, _zs: &[Complex64], i: usize) -> Complex64 {
+            if i == 0 { Complex64::new(f64::NAN, 0.) } else { Complex64::zero() }
+        }
+        let (_, iters, is_converged) = Solver::default().with_max_iters(10).solve_by_simultaneous_iterations(&p_2357(), correction);
+        assert!(!is_converged);
+        assert_eq!(10, iters);
+    }
+}
@@ -0,0 +1,157 @@
+//! Real roots of polynomial with real coefficients: Sturm sequence, counting and isolation of real roots.
+
+use crate::polynomial::Polynomial;
+
+
+impl Polynomial {
+    /// Sturm sequence: `p_0 = p`, `p_1 = p'`, `p_(i+1) = -(p_(i-1) mod p_i)`, until remainder is zero.
+    pub fn sturm_sequence(&self) -> Vec<Polynomial> {
+        assert!(self.is_real(), "Sturm sequence is for polynomials with real coefficients");
+        let mut sequence = vec![self.clone()];
+        let mut next = self.derivative();
+        while !next.is_zero() {
+            let prev = sequence.last().unwrap();
+            let remainder = (prev % &next).trimmed_by(Self::GCD_TOLERANCE * prev.norm_inf());
+            sequence.push(next);
+            next = -remainder;
+        }
+        sequence
+    }
+
+    /// Number of distinct real roots in `(l, r]` by Sturm's theorem, `l` and `r` can be infinite.
+    pub fn count_real_roots_in(&self, l: f64, r: f64) -> usize {
+        count_real_roots_in(&self.sturm_sequence(), l, r)
+    }
+
+    /// Number of distinct real roots.
+    pub fn count_real_roots(&self) -> usize {
+        self.count_real_roots_in(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Isolating intervals `(l, r]`, every one contains exactly one distinct real root, sorted.
+    ///
+    /// They are found by bisection of interval, which contains all roots by [Fujiwara bound](Self::fujiwara_bound),
+    /// and counting roots in halves by Sturm's theorem.
+    pub fn isolate_real_roots(&self) -> Vec<(f64, f64)> {
+        let sequence = self.square_free_sturm_sequence();
+        let bound = if self.degree() == 0 { 1. } else { self.fujiwara_bound() + 1. };
+        let mut intervals = vec![];
+        let mut stack = vec![(-bound, bound, count_real_roots_in(&sequence, -bound, bound))];
+        while let Some((l, r, count)) = stack.pop() {
+            let m = (l + r) / 2.;
+            // if interval can't be divided anymore, then roots are too close to be separated in `f64`:
+            if count == 1 || m <= l || m >= r {
+                if count > 0 { intervals.push((l, r)); }
+                continue;
+            }
+            let count_l = count_real_roots_in(&sequence, l, m);
+            if count_l > 0 { stack.push((l, m, count_l)); }
+            if count > count_l { stack.push((m, r, count - count_l)); }
+        }
+        intervals.sort_by(|a, b| a.0.total_cmp(&b.0));
+        intervals
+    }
+
+    /// Distinct real roots: [isolating intervals](Self::isolate_real_roots) are bisected
+    /// until `r - l <= tolerance * max(1, |l|)`.
+    ///
+    /// Unlike sign-based bisection, this works for roots of even multiplicity too.
+    pub fn find_real_solutions(&self, tolerance: f64) -> Vec<f64> {
+        let sequence = self.square_free_sturm_sequence();
+        self.isolate_real_roots().into_iter()
+            .map(|(mut l, mut r)| {
+                while r - l > tolerance * l.abs().max(1.) {
+                    let m = (l + r) / 2.;
+                    if m <= l || m >= r { break; }
+                    if count_real_roots_in(&sequence, l, m) > 0 { r = m } else { l = m }
+                }
+                (l + r) / 2.
+            })
+            .collect()
+    }
+
+    /// Last element of Sturm sequence is `gcd(p, p')`, and if it isn't constant, then all elements are zero
+    /// at multiple roots, and near them signs are mostly rounding errors. So sequence of `p / gcd(p, p')` is used,
+    /// it has same distinct roots, but all of them are simple.
+    fn square_free_sturm_sequence(&self) -> Vec<Polynomial> {
+        let sequence = self.sturm_sequence();
+        let gcd = sequence.last().unwrap();
+        if gcd.degree() == 0 { sequence } else { (self / gcd).sturm_sequence() }
+    }
+}
+
+
+fn count_real_roots_in(sequence: &[Polynomial], l: f64, r: f64) -> usize {
+    sign_changes_at(sequence, l).saturating_sub(sign_changes_at(sequence, r))
+}
+
+
+/// Number of sign changes in Sturm sequence at `x` (zeros are skipped), `x` can be infinite.
+fn sign_changes_at(sequence: &[Polynomial], x: f64) -> usize {
+    let signs: Vec<bool> = sequence.iter()
+        .map(|p| if x.is_finite() {
+            p.eval_at(x).re
+        } else {
+            // sign of leading term:
+            let sign = p.get_k_last().re;
+            if x < 0. && p.degree() % 2 == 1 { -sign } else { sign }
+        })
+        .filter(|&v| v != 0.)
+        .map(|v| v > 0.)
+        .collect();
+    signs.windows(2).filter(|w| w[0] != w[1]).count()
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (x - 2)(x - 3)(x - 5)(x - 7)
+    fn p_2357() -> Polynomial {
+        Polynomial::new([210, -247, 101, -17, 1])
+    }
+
+    #[test]
+    fn count_real_roots() {
+        assert_eq!(4, p_2357().count_real_roots());
+        assert_eq!(4, p_2357().count_real_roots_in(0., 10.));
+        assert_eq!(2, p_2357().count_real_roots_in(2.5, 5.));
+        // interval is `(l, r]`:
+        assert_eq!(1, p_2357().count_real_roots_in(2., 3.));
+        assert_eq!(0, Polynomial::new([7, 2, 7, 2, 1]).count_real_roots());
+        // (x - 1)(x + 1)², distinct roots are counted:
+        assert_eq!(2, Polynomial::new([-1, -1, 1, 1]).count_real_roots());
+    }
+
+    #[test]
+    fn isolate_real_roots() {
+        let intervals = p_2357().isolate_real_roots();
+        assert_eq!(4, intervals.len());
+        for ((l, r), root) in intervals.into_iter().zip([2., 3., 5., 7.]) {
+            assert!(l < root && root <= r);
+        }
+    }
+
+    #[test]
+    fn find_real_solutions() {
+        let roots = p_2357().find_real_solutions(1e-12);
+        assert_eq!(4, roots.len());
+        for (root, expected) in roots.into_iter().zip([2., 3., 5., 7.]) {
+            assert!((root - expected).abs() < 1e-11);
+        }
+        // (x - 1)(x + 1)², `-1` is root of even multiplicity, so sign doesn't change there:
+        let roots = Polynomial::new([-1, -1, 1, 1]).find_real_solutions(1e-12);
+        assert_eq!(2, roots.len());
+        assert!((roots[0] + 1.).abs() < 1e-11);
+        assert!((roots[1] - 1.).abs() < 1e-11);
+        // close roots: (x - 1)(x - 1.001)(x + 10):
+        let p = &(&Polynomial::new([-1, 1]) * &Polynomial::new([-1.001, 1.])) * &Polynomial::new([10, 1]);
+        let roots = p.find_real_solutions(1e-12);
+        assert_eq!(3, roots.len());
+        assert!((roots[1] - 1.).abs() < 1e-10 && (roots[2] - 1.001).abs() < 1e-10);
+    }
+}
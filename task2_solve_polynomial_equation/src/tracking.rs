@@ -0,0 +1,180 @@
+//! Root locus: continuous trajectories of roots of `p(x; t) = Σ a_i(t) x^i` while parameter `t` varies.
+//!
+//! Roots at every step are found by Newton's method started from roots of previous step, so `i`-th root
+//! is the same trajectory at all steps (unlike [`Roots`](crate::Roots), which are sorted).
+
+use std::fmt::Write;
+
+use num::complex::Complex64;
+
+use crate::{polynomial::Polynomial, solvers::Solver};
+
+
+/// Table of trajectories: `roots[k][i]` is `i`-th root of `p(x; ts[k])`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RootLocus {
+    pub ts: Vec<f64>,
+    pub roots: Vec<Vec<Complex64>>,
+}
+
+impl RootLocus {
+    /// Trajectory of `i`-th root.
+    pub fn trajectory(&self, i: usize) -> Vec<Complex64> {
+        self.roots.iter().map(|roots| roots[i]).collect()
+    }
+
+    /// Row per step, like `t,re_0,im_0,re_1,im_1`, for plotting.
+    pub fn to_csv(&self) -> String {
+        let n = self.roots.first().map_or(0, Vec::len);
+        let mut csv = String::from("t");
+        for i in 0..n {
+            write!(csv, ",re_{i},im_{i}").unwrap();
+        }
+        csv.push('\n');
+        for (t, roots) in self.ts.iter().zip(&self.roots) {
+            write!(csv, "{t}").unwrap();
+            for z in roots {
+                write!(csv, ",{},{}", z.re, z.im).unwrap();
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+
+impl Solver {
+    /// Max number of Newton's iterations per root in one step of [`track_roots`](Self::track_roots).
+    pub const TRACK_ITERS: u32 = 20;
+    /// Max number of halvings of step of [`track_roots`](Self::track_roots).
+    pub const TRACK_MAX_BISECTIONS: u32 = 10;
+
+    /// Roots of `family(t)` for every `t` of `ts`, degree of `family(t)` must not depend on `t`.
+    ///
+    /// Roots at `ts[0]` are found by [`solve`](Self::solve). At next `t` every root is refined by Newton's method
+    /// from its previous value, and step is accepted if every root converges and moves less than third of distance
+    /// to nearest other previous root, so roots can't swap or merge. Otherwise step is halved, and after
+    /// [`TRACK_MAX_BISECTIONS`](Self::TRACK_MAX_BISECTIONS) (near multiple roots) roots are found from scratch
+    /// and matched to previous ones by nearness.
+    pub fn track_roots(&self, family: impl Fn(f64) -> Polynomial, ts: &[f64]) -> RootLocus {
+        let mut roots: Vec<Vec<Complex64>> = Vec::with_capacity(ts.len());
+        if let Some(&t_0) = ts.first() {
+            roots.push(self.solve(&family(t_0)).roots);
+        }
+        for k in 1..ts.len() {
+            let next = self.track_step(&family, ts[k-1], &roots[k-1], ts[k], 0);
+            roots.push(next);
+        }
+        RootLocus { ts: ts.to_vec(), roots }
+    }
+
+    fn track_step(
+        &self,
+        family: &impl Fn(f64) -> Polynomial,
+        t_prev: f64,
+        zs_prev: &[Complex64],
+        t: f64,
+        bisections: u32,
+    ) -> Vec<Complex64> {
+        let p = family(t);
+        assert_eq!(zs_prev.len(), p.degree(), "degree of polynomial changed at t = {t}");
+        if let Some(zs) = self.continued(&p, zs_prev) { return zs; }
+        if bisections < Self::TRACK_MAX_BISECTIONS {
+            let t_mid = (t_prev + t) / 2.;
+            let zs_mid = self.track_step(family, t_prev, zs_prev, t_mid, bisections + 1);
+            return self.track_step(family, t_mid, &zs_mid, t, bisections + 1);
+        }
+        matched(zs_prev, self.solve(&p).roots)
+    }
+
+    /// Roots of `p` by Newton's method from `zs_prev`, if it's safe.
+    fn continued(&self, p: &Polynomial, zs_prev: &[Complex64]) -> Option<Vec<Complex64>> {
+        let solver = self.with_max_iters(Self::TRACK_ITERS);
+        zs_prev.iter().enumerate()
+            .map(|(i, &z_prev)| {
+                let (z, _, is_converged) = solver.find_one_root_by_newton(p, z_prev);
+                let distance = zs_prev.iter().enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, &z_j)| (z_prev - z_j).norm())
+                    .fold(f64::INFINITY, f64::min);
+                (is_converged && (z - z_prev).norm() < distance / 3.).then_some(z)
+            })
+            .collect()
+    }
+}
+
+
+/// Reorders `zs`, so that `zs[i]` is near `zs_prev[i]`: greedily, nearest pairs are matched first.
+fn matched(zs_prev: &[Complex64], zs: Vec<Complex64>) -> Vec<Complex64> {
+    let mut pairs: Vec<(f64, usize, usize)> = zs_prev.iter().enumerate()
+        .flat_map(|(i, z_prev)| zs.iter().enumerate().map(move |(j, z)| ((z - z_prev).norm(), i, j)))
+        .collect();
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut result: Vec<Option<Complex64>> = vec![None; zs_prev.len()];
+    let mut is_used = vec![false; zs.len()];
+    for (_, i, j) in pairs {
+        if result[i].is_none() && !is_used[j] {
+            result[i] = Some(zs[j]);
+            is_used[j] = true;
+        }
+    }
+    result.into_iter().map(Option::unwrap).collect()
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+
+    fn linspace(a: f64, b: f64, n: usize) -> Vec<f64> {
+        (0..n).map(|k| a + (b - a) * k as f64 / (n - 1) as f64).collect()
+    }
+
+    #[test]
+    fn rotating_roots() {
+        // roots of x² - e^(2πit) are ±e^(πit), so at t = 1 they have swapped:
+        let family = |t: f64| Polynomial::from_coefs(vec![-Complex64::from_polar(1., 2. * PI * t), 0.0.into(), 1.0.into()]);
+        let locus = Solver::default().track_roots(family, &linspace(0., 1., 5));
+        assert_eq!(5, locus.roots.len());
+        for (t, roots) in locus.ts.iter().zip(&locus.roots) {
+            assert!((roots[0] + Complex64::from_polar(1., PI * t)).norm() < 1e-14, "{t}: {roots:?}");
+            assert!((roots[1] - Complex64::from_polar(1., PI * t)).norm() < 1e-14, "{t}: {roots:?}");
+        }
+        let sorted = Solver::default().solve(&family(1.)).roots;
+        assert!((locus.roots[4][0] - sorted[1]).norm() < 1e-14);
+    }
+
+    #[test]
+    fn control_loop() {
+        // closed loop of `K / (s (s + 1)(s + 2))` has characteristic polynomial `s³ + 3s² + 2s + K`:
+        // roots break away from real axis at `K ≈ 0.385`, and cross imaginary axis at `K = 6`, `s = ±i√2`:
+        let family = |k: f64| Polynomial::new([k, 2., 3., 1.]);
+        let ks = linspace(0., 10., 41);
+        let locus = Solver::default().track_roots(family, &ks);
+        for (&k, roots) in ks.iter().zip(&locus.roots) {
+            for z in roots {
+                assert!(family(k).eval_at(*z).norm() < 1e-12);
+            }
+            let unstable = roots.iter().filter(|z| z.re > 0.).count();
+            assert_eq!(if k <= 6. { 0 } else { 2 }, unstable, "{k}");
+        }
+        // trajectories are continuous:
+        for i in 0..3 {
+            let trajectory = locus.trajectory(i);
+            assert!(trajectory.windows(2).all(|w| (w[1] - w[0]).norm() < 0.6), "{trajectory:?}");
+        }
+        // root on negative real axis stays there:
+        assert!(locus.trajectory(0).iter().all(|z| z.im == 0. && z.re <= -2.));
+    }
+
+    #[test]
+    fn to_csv() {
+        let locus = Solver::default().track_roots(|t| Polynomial::new([-t, 1.]), &[0., 0.5, 1.]);
+        assert_eq!("t,re_0,im_0\n0,0,0\n0.5,0.5,0\n1,1,0\n", locus.to_csv());
+    }
+}
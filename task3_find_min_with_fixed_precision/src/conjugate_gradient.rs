@@ -0,0 +1,56 @@
+//! Nonlinear conjugate gradient: `d_k = -∇f_k + β_k d_(k-1)`, so for quadratic `f` with exact line search
+//! directions are conjugate, and min is found in `n` iterations.
+
+use nalgebra::DVector;
+
+use crate::{descent::Directions, objective::Objective};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConjugateGradientFormula {
+    /// `β = |∇f_k|² / |∇f_(k-1)|²`.
+    FletcherReeves,
+    /// `β = max(0, ∇f_k · (∇f_k - ∇f_(k-1)) / |∇f_(k-1)|²)`, it restarts by itself, when progress is slow.
+    PolakRibiere,
+}
+
+
+/// Restarts with antigradient every `n` iterations.
+pub(crate) struct ConjugateGradient {
+    formula: ConjugateGradientFormula,
+    /// `(∇f_(k-1), d_(k-1))`.
+    prev: Option<(DVector<f64>, DVector<f64>)>,
+    iters_since_restart: usize,
+}
+
+impl ConjugateGradient {
+    pub(crate) fn new(formula: ConjugateGradientFormula) -> Self {
+        Self { formula, prev: None, iters_since_restart: 0 }
+    }
+}
+
+impl Directions for ConjugateGradient {
+    fn direction(&mut self, _objective: &Objective, _x: &DVector<f64>, gradient: &DVector<f64>) -> (DVector<f64>, Option<f64>) {
+        if self.iters_since_restart >= gradient.len() { self.reset(); }
+        let direction = match &self.prev {
+            None => -gradient,
+            Some((gradient_prev, direction_prev)) => {
+                let beta = match self.formula {
+                    ConjugateGradientFormula::FletcherReeves => gradient.norm_squared() / gradient_prev.norm_squared(),
+                    ConjugateGradientFormula::PolakRibiere => {
+                        (gradient.dot(&(gradient - gradient_prev)) / gradient_prev.norm_squared()).max(0.)
+                    }
+                };
+                -gradient + beta * direction_prev
+            }
+        };
+        self.prev = Some((gradient.clone(), direction.clone()));
+        self.iters_since_restart += 1;
+        (direction, None)
+    }
+
+    fn reset(&mut self) {
+        self.prev = None;
+        self.iters_since_restart = 0;
+    }
+}
@@ -0,0 +1,71 @@
+//! Coordinate descent: minimization along every axis in turn.
+
+use nalgebra::DVector;
+
+use crate::{
+    line_search::LineSearch,
+    minimizer::{Minimizer, Minimum, StopReason},
+    objective::Objective,
+};
+
+
+impl Minimizer {
+    /// Iteration is sweep over all axes. If no axis gives decrease of `f`, though gradient isn't small,
+    /// or `f` isn't finite after sweep, then it stops by [`LineSearchFailed`](StopReason::LineSearchFailed).
+    pub(crate) fn minimize_by_coordinate_descent(&self, objective: &Objective, x_start: DVector<f64>) -> Minimum {
+        let mut x = x_start;
+        let mut value = objective.value(&x);
+        let mut iter = 0;
+        loop {
+            iter += 1;
+            let x_prev = x.clone();
+            let value_prev = value;
+            for axis in 0..x.len() {
+                x = find_min_along_axis(objective, x, axis);
+            }
+            value = objective.value(&x);
+            if !value.is_finite() { return Minimum::new(x_prev, value_prev, iter, StopReason::LineSearchFailed); }
+            let step = (&x - &x_prev).norm();
+            if step == 0. && objective.gradient(&x).norm() > self.gradient_tolerance {
+                return Minimum::new(x, value, iter, StopReason::LineSearchFailed);
+            }
+            if let Some(stop_reason) = self.stop_reason(objective, iter, step, value - value_prev) {
+                return Minimum::new(x, value, iter, stop_reason);
+            }
+        }
+    }
+}
+
+
+/// By Newton's method on derivative along `axis`: step is `f' / f''`, derivatives are by central differences.
+/// If `f` isn't convex along axis, then Newton's step may go to max, so min is found by [`Brent`](LineSearch::Brent)
+/// line search in direction of decrease instead.
+fn find_min_along_axis(objective: &Objective, x: DVector<f64>, axis: usize) -> DVector<f64> {
+    const MAX_ITERATION: usize = 20;
+    const STEP_TOLERANCE: f64 = 1e-4;
+    const DERIVATIVE_DELTA: f64 = 1e-4;
+
+    let mut x = x;
+    for _ in 0..MAX_ITERATION {
+        let delta = DERIVATIVE_DELTA * x[axis].abs().max(1.);
+        let value = objective.value(&x);
+        let mut x_delta = x.clone();
+        x_delta[axis] = x[axis] + delta;
+        let value_plus = objective.value(&x_delta);
+        x_delta[axis] = x[axis] - delta;
+        let value_minus = objective.value(&x_delta);
+        let derivative = (value_plus - value_minus) / (2. * delta);
+        let second_derivative = (value_plus - 2. * value + value_minus) / delta.powi(2);
+        if second_derivative.is_nan() || second_derivative <= 0. {
+            let mut direction = DVector::zeros(x.len());
+            direction[axis] = -derivative.signum();
+            let initial_step = delta / DERIVATIVE_DELTA;
+            let (step, _) = LineSearch::Brent.search(objective, &x, &direction, value, -derivative.abs(), initial_step);
+            return x + step * direction;
+        }
+        let step = derivative / second_derivative;
+        x[axis] -= step;
+        if step.abs() < STEP_TOLERANCE { return x; }
+    }
+    x
+}
@@ -0,0 +1,74 @@
+//! Descent methods: `x_(k+1) = x_k + α_k d_k`, where `d_k` is descent direction and `α_k` is found by line search.
+
+use nalgebra::DVector;
+
+use crate::{
+    minimizer::{Minimizer, Minimum, StopReason},
+    objective::Objective,
+};
+
+
+/// Rule of choosing descent directions, which can accumulate information about `f`.
+pub(crate) trait Directions {
+    /// Direction at `x` with `gradient`, and initial step of line search, if method knows good one.
+    fn direction(&mut self, objective: &Objective, x: &DVector<f64>, gradient: &DVector<f64>) -> (DVector<f64>, Option<f64>);
+
+    /// After step `s = x_(k+1) - x_k`, which changed gradient by `y = ∇f_(k+1) - ∇f_k`.
+    fn update(&mut self, _s: &DVector<f64>, _y: &DVector<f64>) {}
+
+    /// Forgets accumulated information, so next direction is antigradient.
+    fn reset(&mut self) {}
+}
+
+
+impl Minimizer {
+    /// If direction isn't descent one, then `directions` are reset, and if it doesn't help or line search
+    /// fails, then it stops by [`LineSearchFailed`](StopReason::LineSearchFailed). Without known initial step it's
+    /// `α_(k-1) φ'_(k-1)(0) / φ'_k(0)`, so first-order change of `f` is same as on previous iteration,
+    /// and first one is unit length.
+    pub(crate) fn minimize_by_descent(
+        &self,
+        objective: &Objective,
+        x_start: DVector<f64>,
+        mut directions: impl Directions,
+    ) -> Minimum {
+        let mut x = x_start;
+        let mut value = objective.value(&x);
+        let mut gradient = objective.gradient(&x);
+        let mut iter = 0;
+        let mut prev: Option<(f64, f64)> = None; // `(α, slope)` of previous iteration
+        loop {
+            if gradient.norm() <= self.gradient_tolerance {
+                return Minimum::new(x, value, iter, StopReason::Gradient);
+            }
+            iter += 1;
+            let (mut direction, mut initial_step) = directions.direction(objective, &x, &gradient);
+            let mut slope = gradient.dot(&direction);
+            if slope.is_nan() || slope >= 0. {
+                directions.reset();
+                (direction, initial_step) = directions.direction(objective, &x, &gradient);
+                slope = gradient.dot(&direction);
+                if slope.is_nan() || slope >= 0. {
+                    return Minimum::new(x, value, iter, StopReason::LineSearchFailed);
+                }
+            }
+            let initial_step = initial_step.unwrap_or_else(|| match prev {
+                Some((step_prev, slope_prev)) => step_prev * slope_prev / slope,
+                None => 1. / direction.norm(),
+            });
+            let (step, value_new) = self.line_search.search(objective, &x, &direction, value, slope, initial_step);
+            if step == 0. { return Minimum::new(x, value, iter, StopReason::LineSearchFailed); }
+            let s = step * &direction;
+            x += &s;
+            let value_prev = value;
+            value = value_new;
+            prev = Some((step, slope));
+            if let Some(stop_reason) = self.stop_reason(objective, iter, s.norm(), value - value_prev) {
+                return Minimum::new(x, value, iter, stop_reason);
+            }
+            let gradient_new = objective.gradient(&x);
+            directions.update(&s, &(&gradient_new - &gradient));
+            gradient = gradient_new;
+        }
+    }
+}
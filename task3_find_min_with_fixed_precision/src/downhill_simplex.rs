@@ -0,0 +1,70 @@
+//! Downhill simplex: derivative-free, simplex of `n + 1` points moves away from worst point.
+
+use nalgebra::DVector;
+
+use crate::{
+    extensions::{Avg, IndexOfMax, MirrorRelativeTo},
+    minimizer::{Minimizer, Minimum},
+    objective::Objective,
+};
+
+
+impl Minimizer {
+    /// Result is center of simplex. Step is size of simplex (max distance from best point),
+    /// and value change is spread of values on it.
+    pub(crate) fn minimize_by_downhill_simplex(&self, objective: &Objective, x_start: DVector<f64>) -> Minimum {
+        const INITIAL_SIMPLEX_SCALE: f64 = 2.0;
+        const LERP_T: f64 = 0.5;
+
+        // `x_start + scale e_i` and `x_start - scale (1, …, 1)`, so `x_start` is center:
+        let n = x_start.len();
+        let mut points: Vec<(DVector<f64>, f64)> = (0..=n)
+            .map(|i| {
+                let shift = if i < n { DVector::from_fn(n, |j, _| if j == i { 1. } else { 0. }) } else { -DVector::repeat(n, 1.) };
+                &x_start + INITIAL_SIMPLEX_SCALE * shift
+            })
+            .map(|p| { let value = objective.value(&p); (p, value) })
+            .collect();
+        let mut iter = 0;
+        loop {
+            iter += 1;
+            let values: Vec<f64> = points.iter().map(|(_, value)| *value).collect();
+            let index_of_max = values.index_of_max().unwrap();
+            let (point_max, value_at_max) = points[index_of_max].clone();
+            let center: DVector<f64> = points.iter().enumerate()
+                .filter(|&(i, _)| i != index_of_max)
+                .map(|(_, (p, _))| p.clone())
+                .collect::<Vec<_>>()
+                .into_iter()
+                .avg();
+
+            let point_symmetric = point_max.mirror_relative_to_center(&center);
+            let value_at_symmetric = objective.value(&point_symmetric);
+            if value_at_symmetric < value_at_max {
+                points[index_of_max] = (point_symmetric, value_at_symmetric);
+            } else {
+                let point_lerp = point_max.lerp(&center, LERP_T);
+                let value_at_lerp = objective.value(&point_lerp);
+                if value_at_lerp < value_at_max {
+                    points[index_of_max] = (point_lerp, value_at_lerp);
+                } else {
+                    // without shrinking simplex can degenerate, so that worst point is center of others:
+                    let point_best = points.iter().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap().0.clone();
+                    for (p, value) in points.iter_mut().filter(|(p, _)| *p != point_best) {
+                        *p = p.lerp(&point_best, LERP_T);
+                        *value = objective.value(p);
+                    }
+                }
+            }
+
+            let (point_min, value_at_min) = points.iter().min_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+            let size = points.iter().map(|(p, _)| (p - point_min).norm()).fold(0., f64::max);
+            let spread = points.iter().map(|(_, value)| value - value_at_min).fold(0., f64::max);
+            if let Some(stop_reason) = self.stop_reason(objective, iter, size, spread) {
+                let point: DVector<f64> = points.into_iter().map(|(p, _)| p).collect::<Vec<_>>().into_iter().avg();
+                let value = objective.value(&point);
+                return Minimum::new(point, value, iter, stop_reason);
+            }
+        }
+    }
+}
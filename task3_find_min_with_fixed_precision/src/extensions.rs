@@ -0,0 +1,86 @@
+//! Extensions for vectors and slices.
+
+use std::{iter::Sum, ops::Div};
+
+use nalgebra::DVector;
+
+
+pub trait Avg<T> {
+    /// Calculates average.
+    fn avg(self) -> T;
+}
+impl<T, I> Avg<T> for I
+where
+    I: ExactSizeIterator<Item = T>,
+    T: Div<f64, Output = T> + Sum<T>,
+{
+    /// Calculates average of elements of iterator with known length.
+    fn avg(self) -> T {
+        let self_len = self.len();
+        self.sum::<T>() / self_len as f64
+    }
+}
+
+pub trait MirrorRelativeTo {
+    fn mirror_relative_to(&self, a: &Self, b: &Self) -> Self;
+    fn mirror_relative_to_center(&self, center: &Self) -> Self;
+}
+impl MirrorRelativeTo for DVector<f64> {
+    fn mirror_relative_to(&self, a: &Self, b: &Self) -> Self {
+        let ab = (a + b) / 2.;
+        self.mirror_relative_to_center(&ab)
+    }
+    fn mirror_relative_to_center(&self, center: &Self) -> Self {
+        self + 2.*(center - self)
+    }
+}
+
+pub trait IndexOfMax {
+    /// Returns index of element with maximum value.
+    fn index_of_max(&self) -> Option<usize>;
+}
+impl<T: PartialOrd> IndexOfMax for [T] {
+    /// Returns index of element with maximum value for slice.
+    fn index_of_max(&self) -> Option<usize> {
+        match self.len() {
+            0 => None,
+            1 => Some(0),
+            _ => {
+                let mut index_of_max = 0;
+                for i in 1..self.len() {
+                    if self[i] > self[index_of_max] {
+                        index_of_max = i;
+                    }
+                }
+                Some(index_of_max)
+            }
+        }
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dvector;
+
+    use super::*;
+
+    #[test]
+    fn mirror_relative_to() {
+        assert_eq!(dvector![-1., 0.], dvector![1., 0.].mirror_relative_to(&dvector![0., 1.], &dvector![0., -1.]));
+        assert_eq!(dvector![-1., 0.], dvector![1., 0.].mirror_relative_to(&dvector![0., -1.], &dvector![0., 1.]));
+        assert_eq!(dvector![1., 0.], dvector![-1., 0.].mirror_relative_to(&dvector![0., 1.], &dvector![0., -1.]));
+        assert_eq!(dvector![1., 0.], dvector![-1., 0.].mirror_relative_to(&dvector![0., -1.], &dvector![0., 1.]));
+
+        assert_eq!(dvector![0., -1.], dvector![0., 1.].mirror_relative_to(&dvector![1., 0.], &dvector![-1., 0.]));
+        assert_eq!(dvector![0., -1.], dvector![0., 1.].mirror_relative_to(&dvector![-1., 0.], &dvector![1., 0.]));
+        assert_eq!(dvector![0., 1.], dvector![0., -1.].mirror_relative_to(&dvector![1., 0.], &dvector![-1., 0.]));
+        assert_eq!(dvector![0., 1.], dvector![0., -1.].mirror_relative_to(&dvector![-1., 0.], &dvector![1., 0.]));
+
+        assert_eq!(dvector![1., 1.], dvector![0., 0.].mirror_relative_to(&dvector![1., 0.], &dvector![0., 1.]));
+        assert_eq!(dvector![2., 2.], dvector![0., 0.].mirror_relative_to(&dvector![2., 0.], &dvector![0., 2.]));
+    }
+}
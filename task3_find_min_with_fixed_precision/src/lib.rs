@@ -0,0 +1,23 @@
+//! Library for finding min of functions of many variables.
+
+pub mod conjugate_gradient;
+pub mod coordinate_descent;
+pub mod descent;
+pub mod downhill_simplex;
+pub mod extensions;
+pub mod line_search;
+pub mod minimizer;
+pub mod modified_cholesky;
+pub mod newton;
+pub mod objective;
+pub mod quasi_newton;
+pub mod steepest_descent;
+pub mod trust_region;
+
+pub use crate::{
+    conjugate_gradient::ConjugateGradientFormula,
+    line_search::LineSearch,
+    minimizer::{Method, Minimizer, Minimum, StopReason},
+    objective::Objective,
+    trust_region::TrustRegionSubproblem,
+};
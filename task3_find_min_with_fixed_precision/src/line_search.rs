@@ -0,0 +1,288 @@
+//! Line searches: step `α` along descent direction `d`, which minimizes (or sufficiently decreases)
+//! `φ(α) = f(x + α d)`. See Nocedal, Wright "Numerical Optimization", chapter 3, and "Numerical Recipes", chapter 10.
+
+use nalgebra::DVector;
+
+use crate::objective::Objective;
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineSearch {
+    /// Min of `φ` is bracketed, then bracket is shrunk by golden ratio. Derivative-free, convergence is linear.
+    GoldenSection,
+    /// Brent's method: parabolic interpolation, safeguarded by golden-section steps. Derivative-free.
+    Brent,
+    /// Backtracking: step is halved until Armijo's condition of sufficient decrease
+    /// `φ(α) <= φ(0) + c_1 α φ'(0)` holds.
+    Armijo,
+    /// Step satisfies strong Wolfe conditions: Armijo's one and `|φ'(α)| <= c2 |φ'(0)|`,
+    /// it's found by bracketing and zooming with quadratic interpolation.
+    Wolfe { c2: f64 },
+}
+
+impl LineSearch {
+    /// Relative precision of step for [`GoldenSection`](Self::GoldenSection) and [`Brent`](Self::Brent).
+    pub const TOLERANCE: f64 = 1e-6;
+    pub const MAX_ITERS: u32 = 100;
+    /// Constant of sufficient decrease.
+    pub const C1: f64 = 1e-4;
+    /// Constant of curvature condition for Newton's and quasi-Newton methods, they accept almost any step.
+    pub const C2: f64 = 0.9;
+    /// Constant of curvature condition for conjugate gradient, it needs almost exact line search.
+    pub const C2_CONJUGATE_GRADIENT: f64 = 0.1;
+
+    /// Returns `(α, φ(α))`, search starts from `initial_step`, `value = φ(0)` and `slope = φ'(0) < 0`.
+    ///
+    /// If `d` isn't descent direction or decrease isn't found, then `α = 0`.
+    pub fn search(
+        &self,
+        objective: &Objective,
+        x: &DVector<f64>,
+        direction: &DVector<f64>,
+        value: f64,
+        slope: f64,
+        initial_step: f64,
+    ) -> (f64, f64) {
+        if slope.is_nan() || slope >= 0. { return (0., value); }
+        let phi = |alpha: f64| objective.value(&(x + alpha * direction));
+        let phi_derivative = |alpha: f64| objective.derivative_along(&(x + alpha * direction), direction);
+        match self {
+            LineSearch::GoldenSection => match bracket(&phi, value, initial_step) {
+                Some(bracket) => golden_section(&phi, bracket),
+                None => (0., value),
+            },
+            LineSearch::Brent => match bracket(&phi, value, initial_step) {
+                Some(bracket) => brent(&phi, bracket),
+                None => (0., value),
+            },
+            LineSearch::Armijo => backtracking(&phi, value, slope, initial_step),
+            LineSearch::Wolfe { c2 } => wolfe(&phi, &phi_derivative, value, slope, initial_step, *c2),
+        }
+    }
+}
+
+
+/// `1 / golden ratio`.
+const GOLDEN_R: f64 = 0.618_033_988_749_894_8;
+/// `1 - 1 / golden ratio`.
+const GOLDEN_C: f64 = 1. - GOLDEN_R;
+/// Absolute precision of step near `0`.
+const STEP_EPSILON: f64 = 1e-14;
+
+
+/// `[(a, φ(a)), (b, φ(b)), (c, φ(c))]`, where `a < b < c` and `φ(b) < φ(a)`, `φ(b) <= φ(c)`.
+type Bracket = [(f64, f64); 3];
+
+/// Step is shrunk until `φ(b) < φ(0)`, then it's expanded by golden ratio until `φ` increases.
+fn bracket(phi: &impl Fn(f64) -> f64, value: f64, initial_step: f64) -> Option<Bracket> {
+    let a = (0., value);
+    let mut b = (initial_step, phi(initial_step));
+    let mut c = None;
+    for _ in 0..LineSearch::MAX_ITERS {
+        if b.1 < a.1 { break; }
+        c = Some(b);
+        let step = GOLDEN_C * b.0;
+        b = (step, phi(step));
+    }
+    if b.1 >= a.1 { return None; }
+    let (mut a, mut b) = (a, b);
+    let mut c = c.unwrap_or_else(|| { let step = b.0 + (b.0 - a.0) / GOLDEN_R; (step, phi(step)) });
+    for _ in 0..LineSearch::MAX_ITERS {
+        if c.1 >= b.1 { break; }
+        let step = c.0 + (c.0 - b.0) / GOLDEN_R;
+        (a, b, c) = (b, c, (step, phi(step)));
+    }
+    Some([a, b, c])
+}
+
+
+fn golden_section(phi: &impl Fn(f64) -> f64, [a, b, c]: Bracket) -> (f64, f64) {
+    // `x0 < x1 < x2 < x3`, and `x1`, `x2` divide bigger interval by golden ratio:
+    let (mut x0, mut x3) = (a.0, c.0);
+    let ((mut x1, mut f1), (mut x2, mut f2)) = if c.0 - b.0 > b.0 - a.0 {
+        let x2 = b.0 + GOLDEN_C * (c.0 - b.0);
+        (b, (x2, phi(x2)))
+    } else {
+        let x1 = b.0 - GOLDEN_C * (b.0 - a.0);
+        ((x1, phi(x1)), b)
+    };
+    for _ in 0..LineSearch::MAX_ITERS {
+        if x3 - x0 <= LineSearch::TOLERANCE * (x1.abs() + x2.abs()) + STEP_EPSILON { break; }
+        if f2 < f1 {
+            (x0, x1, f1) = (x1, x2, f2);
+            x2 = GOLDEN_R * x2 + GOLDEN_C * x3;
+            f2 = phi(x2);
+        } else {
+            (x3, x2, f2) = (x2, x1, f1);
+            x1 = GOLDEN_R * x1 + GOLDEN_C * x0;
+            f1 = phi(x1);
+        }
+    }
+    if f1 < f2 { (x1, f1) } else { (x2, f2) }
+}
+
+
+/// By "Numerical Recipes": `x` is best point, `w` is second best, `v` is previous `w`,
+/// and parabola through them is used, if its step is inside bracket and is less than half of step before last.
+fn brent(phi: &impl Fn(f64) -> f64, [(a, _), best, (c, _)]: Bracket) -> (f64, f64) {
+    let (mut a, mut b) = (a, c);
+    let (mut x, mut fx) = best;
+    let ((mut w, mut fw), (mut v, mut fv)) = ((x, fx), (x, fx));
+    let (mut d, mut e): (f64, f64) = (0., 0.);
+    for _ in 0..LineSearch::MAX_ITERS {
+        let xm = (a + b) / 2.;
+        let tol1 = LineSearch::TOLERANCE * x.abs() + STEP_EPSILON;
+        let tol2 = 2. * tol1;
+        if (x - xm).abs() <= tol2 - (b - a) / 2. { break; }
+        let golden_step = |x: f64| if x >= xm { a - x } else { b - x };
+        if e.abs() > tol1 {
+            let r = (x - w) * (fx - fv);
+            let q = (x - v) * (fx - fw);
+            let p = (x - v) * q - (x - w) * r;
+            let (p, q) = if q - r > 0. { (-p, 2. * (q - r)) } else { (p, -2. * (q - r)) };
+            let e_prev = e;
+            e = d;
+            if p.abs() >= (q * e_prev / 2.).abs() || p <= q * (a - x) || p >= q * (b - x) {
+                e = golden_step(x);
+                d = GOLDEN_C * e;
+            } else {
+                d = p / q;
+                let u = x + d;
+                if u - a < tol2 || b - u < tol2 { d = tol1.copysign(xm - x); }
+            }
+        } else {
+            e = golden_step(x);
+            d = GOLDEN_C * e;
+        }
+        let u = if d.abs() >= tol1 { x + d } else { x + tol1.copysign(d) };
+        let fu = phi(u);
+        if fu <= fx {
+            if u >= x { a = x; } else { b = x; }
+            ((v, fv), (w, fw), (x, fx)) = ((w, fw), (x, fx), (u, fu));
+        } else {
+            if u < x { a = u; } else { b = u; }
+            if fu <= fw || w == x {
+                ((v, fv), (w, fw)) = ((w, fw), (u, fu));
+            } else if fu <= fv || v == x || v == w {
+                (v, fv) = (u, fu);
+            }
+        }
+    }
+    (x, fx)
+}
+
+
+fn backtracking(phi: &impl Fn(f64) -> f64, value: f64, slope: f64, initial_step: f64) -> (f64, f64) {
+    const SHRINK: f64 = 0.5;
+    let mut alpha = initial_step;
+    for _ in 0..LineSearch::MAX_ITERS {
+        let value_alpha = phi(alpha);
+        if value_alpha <= value + LineSearch::C1 * alpha * slope { return (alpha, value_alpha); }
+        alpha *= SHRINK;
+    }
+    (0., value)
+}
+
+
+/// Nocedal, Wright, algorithms 3.5 and 3.6: step is doubled until bracket of acceptable steps is found,
+/// then it's zoomed.
+fn wolfe(
+    phi: &impl Fn(f64) -> f64,
+    phi_derivative: &impl Fn(f64) -> f64,
+    value: f64,
+    slope: f64,
+    initial_step: f64,
+    c2: f64,
+) -> (f64, f64) {
+    let is_sufficient_decrease = |alpha: f64, value_alpha: f64| value_alpha <= value + LineSearch::C1 * alpha * slope;
+    let is_curvature = |slope_alpha: f64| slope_alpha.abs() <= -c2 * slope;
+    let zoom = |mut lo: (f64, f64, f64), mut hi: (f64, f64)| -> (f64, f64) {
+        // `lo = (α, φ(α), φ'(α))` satisfies sufficient decrease and has least `φ`, `φ'(lo) (hi - lo) < 0`:
+        for _ in 0..LineSearch::MAX_ITERS {
+            let delta = hi.0 - lo.0;
+            // min of parabola through `φ(lo)`, `φ'(lo)` and `φ(hi)`, safeguarded:
+            let denominator = 2. * (hi.1 - lo.1 - lo.2 * delta);
+            let t = if denominator > 0. { -lo.2 * delta / denominator } else { 0.5 };
+            let alpha = lo.0 + t.clamp(0.1, 0.9) * delta;
+            let value_alpha = phi(alpha);
+            if !is_sufficient_decrease(alpha, value_alpha) || value_alpha >= lo.1 {
+                hi = (alpha, value_alpha);
+            } else {
+                let slope_alpha = phi_derivative(alpha);
+                if is_curvature(slope_alpha) { return (alpha, value_alpha); }
+                if slope_alpha * delta >= 0. { hi = (lo.0, lo.1); }
+                lo = (alpha, value_alpha, slope_alpha);
+            }
+            if (hi.0 - lo.0).abs() <= STEP_EPSILON { break; }
+        }
+        (lo.0, lo.1)
+    };
+    let mut prev = (0., value, slope);
+    let mut alpha = initial_step;
+    for i in 0..LineSearch::MAX_ITERS {
+        let value_alpha = phi(alpha);
+        if !is_sufficient_decrease(alpha, value_alpha) || (i > 0 && value_alpha >= prev.1) {
+            return zoom(prev, (alpha, value_alpha));
+        }
+        let slope_alpha = phi_derivative(alpha);
+        if is_curvature(slope_alpha) { return (alpha, value_alpha); }
+        if slope_alpha >= 0. {
+            return zoom((alpha, value_alpha, slope_alpha), (prev.0, prev.1));
+        }
+        prev = (alpha, value_alpha, slope_alpha);
+        alpha *= 2.;
+    }
+    (prev.0, prev.1)
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dvector;
+
+    use super::*;
+
+    #[test]
+    fn search() {
+        // `φ(α) = (α - 3)² + 1` along `x` with start `x = 0`:
+        let objective = Objective::new(|x: &DVector<f64>| (x[0] - 3.).powi(2) + x[1].powi(2) + 1.);
+        let (x, direction) = (dvector![0., 0.], dvector![1., 0.]);
+        for line_search in [
+            LineSearch::GoldenSection,
+            LineSearch::Brent,
+            LineSearch::Armijo,
+            LineSearch::Wolfe { c2: LineSearch::C2 },
+            LineSearch::Wolfe { c2: LineSearch::C2_CONJUGATE_GRADIENT },
+        ] {
+            for initial_step in [1e-3, 1., 100.] {
+                let (alpha, value) = line_search.search(&objective, &x, &direction, 10., -6., initial_step);
+                assert_eq!(objective.value(&(&x + alpha * &direction)), value);
+                match line_search {
+                    LineSearch::GoldenSection | LineSearch::Brent => {
+                        assert!((alpha - 3.).abs() < 1e-5, "{line_search:?}, {initial_step}: {alpha}");
+                    }
+                    LineSearch::Armijo => assert!(value <= 10. - LineSearch::C1 * alpha * 6., "{initial_step}: {alpha}"),
+                    LineSearch::Wolfe { c2 } => {
+                        assert!(value <= 10. - LineSearch::C1 * alpha * 6., "{initial_step}: {alpha}");
+                        assert!((2. * (alpha - 3.)).abs() <= c2 * 6. + 1e-6, "{c2}, {initial_step}: {alpha}");
+                    }
+                }
+            }
+            // not descent direction:
+            assert_eq!((0., 10.), line_search.search(&objective, &x, &direction, 10., 6., 1.));
+        }
+    }
+
+    #[test]
+    fn brent_is_faster_than_golden_section() {
+        let evals = |line_search: LineSearch| {
+            let objective = Objective::new(|x: &DVector<f64>| x[0].cosh() - 2. * x[0]);
+            line_search.search(&objective, &dvector![0.], &dvector![1.], 1., -2., 1.);
+            objective.f_evals()
+        };
+        assert!(evals(LineSearch::Brent) < evals(LineSearch::GoldenSection) / 2);
+    }
+}
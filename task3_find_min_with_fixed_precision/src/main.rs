@@ -1,291 +1,123 @@
 //! Find min of Rosenbrock's function with fixed precision.
 
-use std::{cmp::Ordering, iter::Sum, ops::Div};
+use nalgebra::{dmatrix, dvector, DMatrix, DVector};
 
-use nalgebra::Vector2;
-
-
-// TODO(refactor): rename to `Vec2d`
-type Vec2 = Vector2<f64>;
+use task3_find_min_with_fixed_precision::{
+    ConjugateGradientFormula,
+    LineSearch,
+    Method,
+    Minimizer,
+    Minimum,
+    Objective,
+    TrustRegionSubproblem,
+};
 
 
 const PRECISION: f64 = 1e-3;
 const SOLUTION: (f64, f64) = (1., 1.);
 
 
-fn f(p: Vec2) -> f64 {
-    (1.-p.x).powi(2) + 100.*(p.y-p.x.powi(2)).powi(2)
+fn f(p: &DVector<f64>) -> f64 {
+    (1.-p[0]).powi(2) + 100.*(p[1]-p[0].powi(2)).powi(2)
 }
 
 
-fn main() {
-    let point_start = Vec2::new(-1.7, 1.7);
-
-    println!("solutions:");
-
-    let (solution, f_evals) = find_min_by_coordinate_descent(point_start);
-    println!("by coordinate descent      : x = {x}\ty = {y}\tf_evals = {fe}", x=solution.x, y=solution.y, fe=f_evals);
-    // answer: x = 0.9995093629951177 , y = 0.9990616715937082 , f_evals = 13506
-
-    let (solution, f_evals) = find_min_by_fastest_descent(point_start);
-    println!("by \"fastest\" descent       : x = {x}\ty = {y}\tf_evals = {fe}", x=solution.x, y=solution.y, fe=f_evals);
-    // answer: x = 1.0004988735118054 , y = 1.0009999995954986 , f_evals = 6727068
-
-    let (solution, f_evals) = find_min_by_downhill_simplex(point_start);
-    println!("by downhill simplex descent: x = {x}\ty = {y}\tf_evals = {fe}", x=solution.x, y=solution.y, fe=f_evals);
-    // answer: x = 1.0004704501887318 , y = 1.000914952298626  , f_evals = 194
-}
-
-
-fn find_min_by_coordinate_descent(point_start: Vec2) -> (Vec2, u64) {
-    const MAX_ITERATION: usize = 20;
-    const DERIVATIVE_TOLERANCE: f64 = 1e-4;
-    const DERIVATIVE_DELTA: f64 = 1e-2;
-
-    fn find_min_along_x(point: Vec2, f_evals: &mut u64) -> Vec2 {
-        fn derivative_along_x(p: Vec2) -> f64 {
-            let mut delta = Vec2::zero();
-            delta.x = DERIVATIVE_DELTA;
-            (f(p+delta) - f(p-delta)) / (2.*DERIVATIVE_DELTA)
-        }
-        let mut p_n_m1 = point; // P_(n-1)
-        let mut p_n    = point; // P_n
-        for _ in 0..MAX_ITERATION {
-            p_n.x = p_n_m1.x - f(p_n_m1) / derivative_along_x(p_n_m1);
-            *f_evals += 3;
-            if (p_n.x - p_n_m1.x).abs() < DERIVATIVE_TOLERANCE { return p_n; }
-            p_n_m1 = p_n;
-        }
-        p_n
-    }
-
-    fn find_min_along_y(point: Vec2, f_evals: &mut u64) -> Vec2 {
-        fn derivative_along_y(p: Vec2) -> f64 {
-            let mut delta = Vec2::zero();
-            delta.y = DERIVATIVE_DELTA;
-            (f(p+delta) - f(p-delta)) / (2.*DERIVATIVE_DELTA)
-        }
-        let mut p_n_m1 = point; // P_(n-1)
-        let mut p_n    = point; // P_n
-        for _ in 0..MAX_ITERATION {
-            p_n.y = p_n_m1.y - f(p_n_m1) / derivative_along_y(p_n_m1);
-            *f_evals += 3;
-            if (p_n.y - p_n_m1.y).abs() < DERIVATIVE_TOLERANCE { return p_n; }
-            p_n_m1 = p_n;
-        }
-        p_n
-    }
-
-    let solution_exact = Vec2::new(SOLUTION.0, SOLUTION.1);
-
-    let mut f_evals: u64 = 0;
-    let mut point = point_start;
-    while !is_precise_enough(point, solution_exact) {
-        point = find_min_along_x(point, &mut f_evals);
-        if is_precise_enough(point, solution_exact) { break; }
-        point = find_min_along_y(point, &mut f_evals);
-    }
-    (point, f_evals)
+fn gradient(p: &DVector<f64>) -> DVector<f64> {
+    dvector![
+        -2.*(1.-p[0]) - 400.*p[0]*(p[1]-p[0].powi(2)),
+        200.*(p[1]-p[0].powi(2))
+    ]
 }
 
 
-fn find_min_by_fastest_descent(point_start: Vec2) -> (Vec2, u64) {
-    fn find_min_along_gradient(point: Vec2, f_evals: &mut u64) -> Vec2 {
-        const MAX_ITERATION: usize = 10;
-        const DERIVATIVE_TOLERANCE: f64 = 1e-3;
-        const DERIVATIVE_DELTA: f64 = 1e-4;
-        const STEP_SCALE: f64 = 2e-3;
-
-        fn derivative_along_direction(p: Vec2, dir: Vec2) -> f64 {
-            let delta = dir.normalize() * DERIVATIVE_DELTA;
-            (f(p+delta) - f(p-delta)) / (2.*DERIVATIVE_DELTA)
-        }
-        fn grad(p: Vec2) -> Vec2 {
-            Vec2::new(
-                derivative_along_direction(p, Vec2::identity_along_x()),
-                derivative_along_direction(p, Vec2::identity_along_y()),
-            )
-        }
-        let grad_dir = grad(point);
-        let mut p_n_m1 = point; // P_(n-1)
-        let mut p_n    = point; // P_n
-        for _ in 0..MAX_ITERATION {
-            p_n = p_n_m1 - STEP_SCALE * grad_dir * f(p_n_m1) / derivative_along_direction(p_n_m1, grad_dir);
-            *f_evals += 3;
-            if (p_n - p_n_m1).norm_squared() < DERIVATIVE_TOLERANCE.powi(2) { return p_n; }
-            p_n_m1 = p_n;
-        }
-        p_n
-    }
-
-    let solution_exact = Vec2::new(SOLUTION.0, SOLUTION.1);
-
-    let mut f_evals: u64 = 0;
-    let mut point = point_start;
-    while !is_precise_enough(point, solution_exact) {
-        point = find_min_along_gradient(point, &mut f_evals);
-    }
-    (point, f_evals)
+fn hessian(p: &DVector<f64>) -> DMatrix<f64> {
+    dmatrix![
+        2. - 400.*p[1] + 1200.*p[0].powi(2), -400.*p[0];
+        -400.*p[0], 200.
+    ]
 }
 
 
-fn find_min_by_downhill_simplex(point_start: Vec2) -> (Vec2, u64) {
-    const INITIAL_SIMPLEX_SCALE: f64 = 2.0;
-    const LERP_T: f64 = 0.5;
-
-    let solution_exact = Vec2::new(SOLUTION.0, SOLUTION.1);
-    let mut f_evals: u64 = 0;
-
-    let points: [Vec2; 3] = [
-        point_start - INITIAL_SIMPLEX_SCALE*Vec2::identity_along_x(),
-        point_start + INITIAL_SIMPLEX_SCALE*Vec2::identity_along_y(),
-        point_start + INITIAL_SIMPLEX_SCALE*Vec2::new(1., -1.),
-    ];
-    let mut points: [(Vec2, f64); 3] = points.map(|p| (p, f(p)));
-    f_evals += 3;
-    trait GetPoints<T, const N: usize> {
-        fn get_points(&self) -> [T; N];
-    }
-    impl GetPoints<Vec2, 3> for [(Vec2, f64); 3] {
-        fn get_points(&self) -> [Vec2; 3] {
-            self.map(|pv| pv.0)
-        }
-    }
-    trait GetValues<T, const N: usize> {
-        fn get_values(&self) -> [T; N];
-    }
-    impl GetValues<f64, 3> for [(Vec2, f64); 3] {
-        fn get_values(&self) -> [f64; 3] {
-            self.map(|pv| pv.1)
-        }
-    }
-    while !is_precise_enough(points.get_points().avg(), solution_exact) {
-        let index_of_max = points.get_values().index_of_max().unwrap();
-        let (point_max, value_at_max) = points[index_of_max];
-
-        let other_points_indices = match index_of_max {
-            0 => [1, 2],
-            1 => [0, 2],
-            2 => [0, 1],
-            _ => unreachable!()
-        };
-        let point_a = points[other_points_indices[0]].0;
-        let point_b = points[other_points_indices[1]].0;
+fn main() {
+    let point_start = dvector![-1.7, 1.7];
+    let objective = Objective::new(f);
 
-        let point_symmetric = point_max.mirror_relative_to(point_a, point_b);
-        let value_at_symmetric = f(point_symmetric);
-        f_evals += 1;
+    println!("solutions:");
 
-        points[index_of_max] = match value_at_symmetric.partial_cmp(&value_at_max).unwrap() {
-            Ordering::Less => {
-                (point_symmetric, value_at_symmetric)
-            }
-            Ordering::Greater | Ordering::Equal => {
-                let point_ab = (point_a + point_b) / 2.;
-                let point_lerp = point_max.lerp(&point_ab, LERP_T);
-                let value_at_lerp = f(point_lerp);
-                f_evals += 1;
-                (point_lerp, value_at_lerp)
-            }
-        };
+    let minimum = Minimizer::new(Method::CoordinateDescent).minimize(&objective, point_start.clone());
+    print_minimum("by coordinate descent", &minimum);
+    // answer: x = 0.9999962148068977 , y = 0.999992429628123  , f_evals = 40307 , iters = 4852
+
+    for (name, line_search) in [
+        ("GoldenSection", LineSearch::GoldenSection),
+        ("Brent", LineSearch::Brent),
+        ("Armijo", LineSearch::Armijo),
+        ("Wolfe", LineSearch::Wolfe { c2: LineSearch::C2 }),
+    ] {
+        let minimum = Minimizer::new(Method::SteepestDescent)
+            .with_line_search(line_search)
+            .minimize(&objective, point_start.clone());
+        print_minimum(&format!("by steepest descent with {name}"), &minimum);
     }
-    (points.get_points().avg(), f_evals)
-}
-
-
-fn is_precise_enough(solution_found: Vec2, solution_exact: Vec2) -> bool {
-    (solution_found.x - solution_exact.x).abs() < PRECISION
-    &&
-    (solution_found.y - solution_exact.y).abs() < PRECISION
-}
-
-
-
-
-
-trait Vec2Exts {
-    fn zero() -> Self;
-    fn identity_along_x() -> Self;
-    fn identity_along_y() -> Self;
-}
-impl Vec2Exts for Vec2 {
-    /// Returns `Vec2 { x: 0, y: 0 }`.
-    fn zero()             -> Self { Vec2::new(0., 0.) }
-    /// Returns `Vec2 { x: 1, y: 0 }`.
-    fn identity_along_x() -> Self { Vec2::new(1., 0.) }
-    /// Returns `Vec2 { x: 0, y: 1 }`.
-    fn identity_along_y() -> Self { Vec2::new(0., 1.) }
-}
-
-trait Avg<T> {
-    /// Calculates average.
-    fn avg(self) -> T;
-}
-impl<T, const N: usize> Avg<T> for [T; N]
-where T: Div<f64, Output = T> + Sum<T> + Sized
-{
-    /// Calculates average of elements for fixed-size array.
-    fn avg(self) -> T {
-        let self_len = self.len();
-        self.into_iter().sum::<T>() / self_len as f64
+    // answers:
+    // GoldenSection: x = 0.9999990637777498 , y = 0.9999981238663034 , f_evals = 427363 , iters = 11550
+    // Brent        : x = 0.9999992435888464 , y = 0.999998484189521  , f_evals = 166265 , iters = 11558
+    // Armijo       : x = 0.9999696354340493 , y = 0.9999393443237695 , f_evals = 40523 , iters = 8095
+    // Wolfe        : x = 0.9999956984847937 , y = 0.9999913739253771 , f_evals = 67687 , iters = 9594
+
+    for (name, method) in [
+        ("by conjugate gradient (Fletcher–Reeves)", Method::ConjugateGradient(ConjugateGradientFormula::FletcherReeves)),
+        ("by conjugate gradient (Polak–Ribière)", Method::ConjugateGradient(ConjugateGradientFormula::PolakRibiere)),
+        ("by BFGS", Method::Bfgs),
+        ("by L-BFGS", Method::Lbfgs { memory: 5 }),
+        ("by damped Newton", Method::Newton),
+        ("by trust region (dogleg)", Method::TrustRegion(TrustRegionSubproblem::Dogleg)),
+        ("by trust region (Steihaug-CG)", Method::TrustRegion(TrustRegionSubproblem::SteihaugCg)),
+    ] {
+        let minimum = Minimizer::new(method).minimize(&objective, point_start.clone());
+        print_minimum(name, &minimum);
     }
+    // answers:
+    // Fletcher–Reeves: x = 0.9999999651509077 , y = 0.9999999301789625 , f_evals = 711 , iters = 65
+    // Polak–Ribière  : x = 1.0000004282616093 , y = 1.0000008582435473 , f_evals = 433 , iters = 38
+    // BFGS           : x = 0.9999999927096788 , y = 0.999999985430973  , f_evals = 324 , iters = 42
+    // L-BFGS         : x = 0.9999999934610144 , y = 0.9999999870284874 , f_evals = 313 , iters = 40
+    // Newton         : x = 0.9999999927998448 , y = 0.9999999855996863 , f_evals = 408 , iters = 25
+    // dogleg         : x = 0.9999999801975578 , y = 0.9999999595328553 , f_evals = 353 , iters = 27
+    // Steihaug-CG    : x = 0.9999998610515616 , y = 0.9999997215765773 , f_evals = 621 , iters = 48
+
+    let objective_with_gradient = Objective::new(f).with_gradient(gradient);
+    let minimum = Minimizer::new(Method::Bfgs).minimize(&objective_with_gradient, point_start.clone());
+    print_minimum("by BFGS with analytic gradient", &minimum);
+    // answer: x = 0.9999999998070982 , y = 0.9999999996110668 , f_evals = 57 , g_evals = 90 , iters = 41
+
+    let objective_with_hessian = Objective::new(f).with_gradient(gradient).with_hessian(hessian);
+    let minimum = Minimizer::new(Method::Newton).minimize(&objective_with_hessian, point_start.clone());
+    print_minimum("by damped Newton with analytic Hessian", &minimum);
+    // answer: x = 0.9999999999999493 , y = 0.9999999999998954 , f_evals = 31 , g_evals = 50 , h_evals = 25 , iters = 25
+
+    let minimum = Minimizer::new(Method::DownhillSimplex).minimize(&objective, point_start);
+    print_minimum("by downhill simplex descent", &minimum);
+    // answer: x = 1.0000000237547042 , y = 1.000000050125353  , f_evals = 613 , iters = 555
 }
 
-trait MirrorRelativeTo {
-    fn mirror_relative_to(&self, a: Vec2, b: Vec2) -> Vec2;
-}
-impl MirrorRelativeTo for Vec2 {
-    fn mirror_relative_to(&self, a: Vec2, b: Vec2) -> Vec2 {
-        let ab = (a + b) / 2.;
-        self + 2.*(ab - self)
-    }
-}
 
-trait IndexOfMax {
-    /// Returns index of element with maximum value.
-    fn index_of_max(&self) -> Option<usize>;
+fn print_minimum(name: &str, minimum: &Minimum) {
+    let (x, y) = (minimum.point[0], minimum.point[1]);
+    println!(
+        "{name:<39}: x = {x}\ty = {y}\tf_evals = {fe}{ge}\titers = {iters}\tstop: {stop:?}{precise}",
+        fe = minimum.f_evals,
+        ge = if minimum.g_evals > 0 { format!(", g_evals = {}", minimum.g_evals) } else { String::new() }
+            + &if minimum.h_evals > 0 { format!(", h_evals = {}", minimum.h_evals) } else { String::new() },
+        iters = minimum.iters,
+        stop = minimum.stop_reason,
+        precise = if is_precise_enough(x, y) { "" } else { "\t(not precise enough)" },
+    );
 }
-impl<T: PartialOrd, const N: usize> IndexOfMax for [T; N] {
-    /// Returns index of element with maximum value for fixed-size array.
-    fn index_of_max(&self) -> Option<usize> {
-        match self.len() {
-            0 => None,
-            1 => Some(0),
-            _ => {
-                let mut index_of_max = 0;
-                for i in 1..self.len() {
-                    if self[i] > self[index_of_max] {
-                        index_of_max = i;
-                    }
-                }
-                Some(index_of_max)
-            }
-        }
-    }
-}
-
-
 
 
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn mirror_relative_to() {
-        assert_eq!(Vec2::new(-1., 0.), Vec2::new(1., 0.).mirror_relative_to(Vec2::new(0., 1.), Vec2::new(0., -1.)));
-        assert_eq!(Vec2::new(-1., 0.), Vec2::new(1., 0.).mirror_relative_to(Vec2::new(0., -1.), Vec2::new(0., 1.)));
-        assert_eq!(Vec2::new(1., 0.), Vec2::new(-1., 0.).mirror_relative_to(Vec2::new(0., 1.), Vec2::new(0., -1.)));
-        assert_eq!(Vec2::new(1., 0.), Vec2::new(-1., 0.).mirror_relative_to(Vec2::new(0., -1.), Vec2::new(0., 1.)));
-
-        assert_eq!(Vec2::new(0., -1.), Vec2::new(0., 1.).mirror_relative_to(Vec2::new(1., 0.), Vec2::new(-1., 0.)));
-        assert_eq!(Vec2::new(0., -1.), Vec2::new(0., 1.).mirror_relative_to(Vec2::new(-1., 0.), Vec2::new(1., 0.)));
-        assert_eq!(Vec2::new(0., 1.), Vec2::new(0., -1.).mirror_relative_to(Vec2::new(1., 0.), Vec2::new(-1., 0.)));
-        assert_eq!(Vec2::new(0., 1.), Vec2::new(0., -1.).mirror_relative_to(Vec2::new(-1., 0.), Vec2::new(1., 0.)));
-
-        assert_eq!(Vec2::new(1., 1.), Vec2::new(0., 0.).mirror_relative_to(Vec2::new(1., 0.), Vec2::new(0., 1.)));
-        assert_eq!(Vec2::new(2., 2.), Vec2::new(0., 0.).mirror_relative_to(Vec2::new(2., 0.), Vec2::new(0., 2.)));
-    }
+fn is_precise_enough(x: f64, y: f64) -> bool {
+    (x - SOLUTION.0).abs() < PRECISION
+    &&
+    (y - SOLUTION.1).abs() < PRECISION
 }
-
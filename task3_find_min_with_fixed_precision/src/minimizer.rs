@@ -0,0 +1,352 @@
+//! Minimizers of functions of many variables and their stopping criteria.
+
+use nalgebra::DVector;
+
+use crate::{
+    conjugate_gradient::{ConjugateGradient, ConjugateGradientFormula},
+    line_search::LineSearch,
+    newton::Newton,
+    objective::Objective,
+    quasi_newton::{Bfgs, Lbfgs},
+    steepest_descent::SteepestDescent,
+    trust_region::TrustRegionSubproblem,
+};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Minimizes along every axis in turn, by Newton's method on derivative along axis,
+    /// or by Brent's line search, where `f` isn't convex along axis.
+    CoordinateDescent,
+    /// Moves along antigradient, step is found by [line search](Minimizer::line_search).
+    SteepestDescent,
+    /// Nonlinear conjugate gradient with line search.
+    ConjugateGradient(ConjugateGradientFormula),
+    /// Quasi-Newton BFGS with line search, it stores `n × n` approximation of inverse Hessian.
+    Bfgs,
+    /// Limited-memory BFGS with line search, it stores last `memory` steps.
+    Lbfgs { memory: usize },
+    /// Damped Newton's method: Newton's direction by Hessian, made positive definite, and line search.
+    Newton,
+    /// Trust-region method with quadratic model by Hessian.
+    TrustRegion(TrustRegionSubproblem),
+    /// Nelder–Mead-like simplex of `n + 1` points: worst point is reflected through center of others,
+    /// or is moved halfway to it, or (if it's still worst) simplex shrinks to best point.
+    /// Derivative-free, so gradient criterion isn't used.
+    DownhillSimplex,
+}
+
+
+/// Why iterations stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// Step `|x_(k+1) - x_k|` (or size of simplex) is `<= x_tolerance`.
+    Step,
+    /// `|f(x_(k+1)) - f(x_k)|` (or spread of values on simplex) is `<= f_tolerance`.
+    ValueChange,
+    /// `|∇f(x_k)|` is `<= gradient_tolerance`.
+    Gradient,
+    MaxIters,
+    MaxFEvals,
+    /// No decrease of `f` is found: direction isn't descent one even after reset, or line search returned zero step,
+    /// or trust region shrank to `x_tolerance`, because `f` or its model isn't finite.
+    LineSearchFailed,
+}
+
+
+/// Found min.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Minimum {
+    pub point: DVector<f64>,
+    pub value: f64,
+    pub iters: u32,
+    pub f_evals: u64,
+    /// Evaluations of user-supplied gradient.
+    pub g_evals: u64,
+    /// Evaluations of user-supplied Hessian.
+    pub h_evals: u64,
+    pub stop_reason: StopReason,
+}
+
+impl Minimum {
+    /// Counters of evaluations are zero, [`Minimizer::minimize`] sets them.
+    pub(crate) fn new(point: DVector<f64>, value: f64, iters: u32, stop_reason: StopReason) -> Self {
+        Self { point, value, iters, f_evals: 0, g_evals: 0, h_evals: 0, stop_reason }
+    }
+
+    /// Stopped by tolerance, not by limit of iterations or evaluations or by failure.
+    pub fn is_converged(&self) -> bool {
+        !matches!(self.stop_reason, StopReason::MaxIters | StopReason::MaxFEvals | StopReason::LineSearchFailed)
+    }
+}
+
+
+/// Iterations stop when any of criteria is satisfied, zero tolerance disables its criterion.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Minimizer {
+    pub method: Method,
+    /// Used by methods, which move along directions. Default is [`Wolfe`](LineSearch::Wolfe) with
+    /// [`C2_CONJUGATE_GRADIENT`](LineSearch::C2_CONJUGATE_GRADIENT) for conjugate gradient and [`C2`](LineSearch::C2) for others.
+    pub line_search: LineSearch,
+    pub x_tolerance: f64,
+    pub f_tolerance: f64,
+    pub gradient_tolerance: f64,
+    pub max_iters: u32,
+    pub max_f_evals: u64,
+}
+
+impl Default for Minimizer {
+    fn default() -> Self {
+        Self::new(Method::DownhillSimplex)
+    }
+}
+
+impl Minimizer {
+    pub const X_TOLERANCE_DEFAULT: f64 = 1e-8;
+    pub const F_TOLERANCE_DEFAULT: f64 = 1e-14;
+    pub const GRADIENT_TOLERANCE_DEFAULT: f64 = 1e-6;
+    pub const MAX_ITERS_DEFAULT: u32 = 100_000;
+    pub const MAX_F_EVALS_DEFAULT: u64 = 10_000_000;
+
+    pub fn new(method: Method) -> Self {
+        let c2 = if matches!(method, Method::ConjugateGradient(_)) { LineSearch::C2_CONJUGATE_GRADIENT } else { LineSearch::C2 };
+        Self {
+            method,
+            line_search: LineSearch::Wolfe { c2 },
+            x_tolerance: Self::X_TOLERANCE_DEFAULT,
+            f_tolerance: Self::F_TOLERANCE_DEFAULT,
+            gradient_tolerance: Self::GRADIENT_TOLERANCE_DEFAULT,
+            max_iters: Self::MAX_ITERS_DEFAULT,
+            max_f_evals: Self::MAX_F_EVALS_DEFAULT,
+        }
+    }
+
+    pub fn with_line_search(self, line_search: LineSearch) -> Self {
+        Self { line_search, ..self }
+    }
+
+    pub fn with_x_tolerance(self, x_tolerance: f64) -> Self {
+        Self { x_tolerance, ..self }
+    }
+
+    pub fn with_f_tolerance(self, f_tolerance: f64) -> Self {
+        Self { f_tolerance, ..self }
+    }
+
+    pub fn with_gradient_tolerance(self, gradient_tolerance: f64) -> Self {
+        Self { gradient_tolerance, ..self }
+    }
+
+    pub fn with_max_iters(self, max_iters: u32) -> Self {
+        Self { max_iters, ..self }
+    }
+
+    pub fn with_max_f_evals(self, max_f_evals: u64) -> Self {
+        Self { max_f_evals, ..self }
+    }
+
+    /// Finds min of `objective` starting from `x_start`, evaluations of result are counted from call.
+    pub fn minimize(&self, objective: &Objective, x_start: DVector<f64>) -> Minimum {
+        let (f_evals_before, g_evals_before, h_evals_before) = (objective.f_evals(), objective.g_evals(), objective.h_evals());
+        // methods compare limit with counter of `objective`:
+        let minimizer = Self { max_f_evals: f_evals_before.saturating_add(self.max_f_evals), ..*self };
+        let mut minimum = match self.method {
+            Method::CoordinateDescent => minimizer.minimize_by_coordinate_descent(objective, x_start),
+            Method::SteepestDescent   => minimizer.minimize_by_descent(objective, x_start, SteepestDescent),
+            Method::ConjugateGradient(formula) => {
+                minimizer.minimize_by_descent(objective, x_start, ConjugateGradient::new(formula))
+            }
+            Method::Bfgs              => minimizer.minimize_by_descent(objective, x_start, Bfgs::default()),
+            Method::Lbfgs { memory }  => minimizer.minimize_by_descent(objective, x_start, Lbfgs::new(memory)),
+            Method::Newton            => minimizer.minimize_by_descent(objective, x_start, Newton),
+            Method::TrustRegion(subproblem) => minimizer.minimize_by_trust_region(objective, x_start, subproblem),
+            Method::DownhillSimplex   => minimizer.minimize_by_downhill_simplex(objective, x_start),
+        };
+        minimum.f_evals = objective.f_evals() - f_evals_before;
+        minimum.g_evals = objective.g_evals() - g_evals_before;
+        minimum.h_evals = objective.h_evals() - h_evals_before;
+        minimum
+    }
+
+    /// Checks criteria after iteration `iter`, which made `step` and changed value by `value_change`.
+    pub(crate) fn stop_reason(&self, objective: &Objective, iter: u32, step: f64, value_change: f64) -> Option<StopReason> {
+        if step <= self.x_tolerance { return Some(StopReason::Step); }
+        if value_change.abs() <= self.f_tolerance { return Some(StopReason::ValueChange); }
+        if iter >= self.max_iters { return Some(StopReason::MaxIters); }
+        if objective.f_evals() >= self.max_f_evals { return Some(StopReason::MaxFEvals); }
+        None
+    }
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::{dmatrix, dvector};
+
+    use super::*;
+
+    const ALL_METHODS: [Method; 10] = [
+        Method::CoordinateDescent,
+        Method::SteepestDescent,
+        Method::ConjugateGradient(ConjugateGradientFormula::FletcherReeves),
+        Method::ConjugateGradient(ConjugateGradientFormula::PolakRibiere),
+        Method::Bfgs,
+        Method::Lbfgs { memory: 5 },
+        Method::Newton,
+        Method::TrustRegion(TrustRegionSubproblem::Dogleg),
+        Method::TrustRegion(TrustRegionSubproblem::SteihaugCg),
+        Method::DownhillSimplex,
+    ];
+
+    const HESSIAN_METHODS: [Method; 3] = [
+        Method::Newton,
+        Method::TrustRegion(TrustRegionSubproblem::Dogleg),
+        Method::TrustRegion(TrustRegionSubproblem::SteihaugCg),
+    ];
+
+    /// `(x - 1)² + 2 (y + 2)² + 3 (z - 0.5)² + 4`.
+    fn quadratic(x: &DVector<f64>) -> f64 {
+        (x[0] - 1.).powi(2) + 2. * (x[1] + 2.).powi(2) + 3. * (x[2] - 0.5).powi(2) + 4.
+    }
+
+    #[test]
+    fn minimize_quadratic() {
+        for method in ALL_METHODS {
+            let objective = Objective::new(quadratic);
+            let minimum = Minimizer::new(method).minimize(&objective, dvector![0., 0., 0.]);
+            assert!(minimum.is_converged(), "{method:?}: {minimum:?}");
+            assert!((&minimum.point - dvector![1., -2., 0.5]).norm() < 1e-3, "{method:?}: {minimum:?}");
+            assert!((minimum.value - 4.).abs() < 1e-6, "{method:?}: {minimum:?}");
+            assert_eq!(objective.f_evals(), minimum.f_evals);
+        }
+        for line_search in [
+            LineSearch::GoldenSection,
+            LineSearch::Brent,
+            LineSearch::Armijo,
+            LineSearch::Wolfe { c2: LineSearch::C2 },
+        ] {
+            let minimizer = Minimizer::new(Method::SteepestDescent).with_line_search(line_search);
+            let minimum = minimizer.minimize(&Objective::new(quadratic), dvector![0., 0., 0.]);
+            assert!(minimum.is_converged(), "{line_search:?}: {minimum:?}");
+            assert!((&minimum.point - dvector![1., -2., 0.5]).norm() < 1e-3, "{line_search:?}: {minimum:?}");
+        }
+    }
+
+    #[test]
+    fn rosenbrock_with_gradient() {
+        let f = |x: &DVector<f64>| (1. - x[0]).powi(2) + 100. * (x[1] - x[0].powi(2)).powi(2);
+        let gradient = |x: &DVector<f64>| dvector![
+            -2. * (1. - x[0]) - 400. * x[0] * (x[1] - x[0].powi(2)),
+            200. * (x[1] - x[0].powi(2))
+        ];
+        for method in [
+            Method::ConjugateGradient(ConjugateGradientFormula::PolakRibiere),
+            Method::Bfgs,
+            Method::Lbfgs { memory: 5 },
+        ] {
+            let objective = Objective::new(f).with_gradient(gradient);
+            let minimum = Minimizer::new(method).with_gradient_tolerance(1e-8).minimize(&objective, dvector![-1.7, 1.7]);
+            assert!((&minimum.point - dvector![1., 1.]).norm() < 1e-6, "{method:?}: {minimum:?}");
+            assert!(minimum.g_evals > 0 && minimum.f_evals < 1000, "{method:?}: {minimum:?}");
+            // with numerical gradient result is same, but there are more evaluations:
+            let objective = Objective::new(f);
+            let minimum_numerical = Minimizer::new(method).with_gradient_tolerance(1e-8).minimize(&objective, dvector![-1.7, 1.7]);
+            assert!((&minimum_numerical.point - dvector![1., 1.]).norm() < 1e-6, "{method:?}: {minimum_numerical:?}");
+            assert_eq!(0, minimum_numerical.g_evals);
+            assert!(minimum_numerical.f_evals > minimum.f_evals);
+        }
+    }
+
+    #[test]
+    fn rosenbrock_with_hessian() {
+        let f = |x: &DVector<f64>| (1. - x[0]).powi(2) + 100. * (x[1] - x[0].powi(2)).powi(2);
+        let gradient = |x: &DVector<f64>| dvector![
+            -2. * (1. - x[0]) - 400. * x[0] * (x[1] - x[0].powi(2)),
+            200. * (x[1] - x[0].powi(2))
+        ];
+        let hessian = |x: &DVector<f64>| dmatrix![
+            2. - 400. * x[1] + 1200. * x[0].powi(2), -400. * x[0];
+            -400. * x[0], 200.
+        ];
+        for method in HESSIAN_METHODS {
+            let objective = Objective::new(f).with_gradient(gradient).with_hessian(hessian);
+            let minimum = Minimizer::new(method).with_gradient_tolerance(1e-10).minimize(&objective, dvector![-1.7, 1.7]);
+            assert!((&minimum.point - dvector![1., 1.]).norm() < 1e-10, "{method:?}: {minimum:?}");
+            assert!(minimum.h_evals > 0 && minimum.iters < 50, "{method:?}: {minimum:?}");
+            // finite-difference Hessian by analytic gradient:
+            let objective = Objective::new(f).with_gradient(gradient);
+            let minimum = Minimizer::new(method).with_gradient_tolerance(1e-10).minimize(&objective, dvector![-1.7, 1.7]);
+            assert!((&minimum.point - dvector![1., 1.]).norm() < 1e-8, "{method:?}: {minimum:?}");
+            assert_eq!(0, minimum.h_evals);
+        }
+    }
+
+    #[test]
+    fn indefinite_hessian() {
+        // `x⁴ - x² + y²` has saddle point at `0` and mins at `x = ±1/√2`, `y = 0`,
+        // and at start `∂²f/∂x² < 0`, so Newton's step without modification goes to saddle point:
+        let objective = Objective::new(|x: &DVector<f64>| x[0].powi(4) - x[0].powi(2) + x[1].powi(2));
+        for method in HESSIAN_METHODS {
+            let minimum = Minimizer::new(method).minimize(&objective, dvector![0.1, 1.]);
+            assert!((&minimum.point - dvector![0.5f64.sqrt(), 0.]).norm() < 1e-6, "{method:?}: {minimum:?}");
+            assert!((minimum.value + 0.25).abs() < 1e-12, "{method:?}: {minimum:?}");
+        }
+    }
+
+    #[test]
+    fn concave_along_axis() {
+        // at start `∂²f/∂x² < 0`, so Newton's step along `x` goes to max:
+        let objective = Objective::new(|x: &DVector<f64>| x[0].powi(4) - x[0].powi(2) + x[1].powi(2));
+        let minimum = Minimizer::new(Method::CoordinateDescent).minimize(&objective, dvector![0.1, 1.]);
+        assert!(minimum.is_converged(), "{minimum:?}");
+        assert!((&minimum.point - dvector![0.5f64.sqrt(), 0.]).norm() < 1e-6, "{minimum:?}");
+        // linear along axis:
+        let objective = Objective::new(|x: &DVector<f64>| (x[0] - 3.).abs());
+        let minimum = Minimizer::new(Method::CoordinateDescent).minimize(&objective, dvector![0.]);
+        assert!((minimum.point[0] - 3.).abs() < 1e-6, "{minimum:?}");
+        // unbounded:
+        let objective = Objective::new(|x: &DVector<f64>| -x[0].powi(2));
+        let minimum = Minimizer::new(Method::CoordinateDescent).minimize(&objective, dvector![0.5]);
+        assert!(!minimum.is_converged(), "{minimum:?}");
+        assert!(minimum.value.is_finite(), "{minimum:?}");
+    }
+
+    #[test]
+    fn stopping_criteria() {
+        let objective = Objective::new(quadratic);
+        let minimum = Minimizer::new(Method::DownhillSimplex).with_max_iters(3).minimize(&objective, dvector![0., 0., 0.]);
+        assert_eq!((StopReason::MaxIters, 3), (minimum.stop_reason, minimum.iters));
+        assert!(!minimum.is_converged());
+        // limit of evaluations is counted from start of minimization:
+        let minimum = Minimizer::new(Method::DownhillSimplex).with_max_f_evals(10).minimize(&objective, dvector![0., 0., 0.]);
+        assert_eq!(StopReason::MaxFEvals, minimum.stop_reason);
+        assert!(minimum.f_evals < 20);
+        let minimum = Minimizer::new(Method::SteepestDescent).with_gradient_tolerance(1e3).minimize(&objective, dvector![0., 0., 0.]);
+        assert_eq!((StopReason::Gradient, 0), (minimum.stop_reason, minimum.iters));
+        let minimum = Minimizer::new(Method::CoordinateDescent).with_x_tolerance(0.1).minimize(&objective, dvector![0., 0., 0.]);
+        assert_eq!(StopReason::Step, minimum.stop_reason);
+    }
+
+    #[test]
+    fn line_search_failed() {
+        // min of `(x - 3)²` is where `f` is NaN, so no decrease is found near `x = 2`:
+        let objective = Objective::new(|x: &DVector<f64>| if x[0] > 2. { f64::NAN } else { (x[0] - 3.).powi(2) });
+        for method in [
+            Method::SteepestDescent,
+            Method::ConjugateGradient(ConjugateGradientFormula::PolakRibiere),
+            Method::Bfgs,
+            Method::Lbfgs { memory: 5 },
+            Method::Newton,
+            Method::TrustRegion(TrustRegionSubproblem::Dogleg),
+            Method::TrustRegion(TrustRegionSubproblem::SteihaugCg),
+        ] {
+            let minimum = Minimizer::new(method).minimize(&objective, dvector![0.]);
+            assert_eq!(StopReason::LineSearchFailed, minimum.stop_reason, "{method:?}: {minimum:?}");
+            assert!(!minimum.is_converged());
+            assert!(minimum.point[0] <= 2. && minimum.value.is_finite(), "{method:?}: {minimum:?}");
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Modified Cholesky factorization: `A + τ I = L Lᵀ`, where `τ >= 0` makes symmetric `A` positive definite,
+//! so Newton's direction `-(A + τ I)⁻¹ ∇f` is descent one even for indefinite Hessian `A`.
+
+use nalgebra::{Cholesky, DMatrix, Dyn};
+
+
+/// Nocedal, Wright, algorithm 3.3: `τ` starts from `0` (or from `β - min a_ii`, if diagonal isn't positive),
+/// and is doubled (at least to `β = 10⁻³ ‖A‖_F`), until Cholesky factorization succeeds. Returns `(L Lᵀ, τ)`,
+/// or `None`, if `A` isn't finite.
+pub fn modified_cholesky(a: &DMatrix<f64>) -> Option<(Cholesky<f64, Dyn>, f64)> {
+    const MAX_ITERS: u32 = 100;
+    if !a.iter().all(|a| a.is_finite()) { return None; }
+    let beta = 1e-3 * a.norm().max(f64::MIN_POSITIVE);
+    let min_diagonal = a.diagonal().min();
+    let mut tau = if min_diagonal > 0. { 0. } else { beta - min_diagonal };
+    for _ in 0..MAX_ITERS {
+        let shifted = a + DMatrix::identity(a.nrows(), a.ncols()) * tau;
+        if let Some(cholesky) = Cholesky::new(shifted) { return Some((cholesky, tau)); }
+        tau = (2. * tau).max(beta);
+    }
+    None
+}
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::dmatrix;
+
+    use super::*;
+
+    #[test]
+    fn modified_cholesky() {
+        // positive definite isn't modified:
+        let a = dmatrix![4., 1.; 1., 3.];
+        let (cholesky, tau) = super::modified_cholesky(&a).unwrap();
+        assert_eq!(0., tau);
+        assert!((cholesky.l() * cholesky.l().transpose() - &a).norm() < 1e-14);
+        // indefinite with eigenvalues `-1` and `3`:
+        let a = dmatrix![1., 2.; 2., 1.];
+        let (cholesky, tau) = super::modified_cholesky(&a).unwrap();
+        assert!(tau > 1. && tau < 3., "{tau}");
+        assert!((cholesky.l() * cholesky.l().transpose() - (&a + DMatrix::identity(2, 2) * tau)).norm() < 1e-12);
+        assert!(super::modified_cholesky(&dmatrix![f64::NAN]).is_none());
+    }
+}
@@ -0,0 +1,19 @@
+//! Damped Newton's method: direction is `-(∇²f + τ I)⁻¹ ∇f` by [modified Cholesky](crate::modified_cholesky),
+//! and step is damped by line search, which starts from full Newton's step.
+
+use nalgebra::DVector;
+
+use crate::{descent::Directions, modified_cholesky::modified_cholesky, objective::Objective};
+
+
+pub(crate) struct Newton;
+
+impl Directions for Newton {
+    /// It's antigradient, if Hessian isn't finite.
+    fn direction(&mut self, objective: &Objective, x: &DVector<f64>, gradient: &DVector<f64>) -> (DVector<f64>, Option<f64>) {
+        match modified_cholesky(&objective.hessian(x)) {
+            Some((cholesky, _)) => (-cholesky.solve(gradient), Some(1.)),
+            None => (-gradient, None),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Objective function with counters of evaluations, its gradient is user-supplied or numerical.
+
+use std::cell::Cell;
+
+use nalgebra::{DMatrix, DVector};
+
+
+type Function<'a> = Box<dyn Fn(&DVector<f64>) -> f64 + 'a>;
+type Gradient<'a> = Box<dyn Fn(&DVector<f64>) -> DVector<f64> + 'a>;
+type Hessian<'a> = Box<dyn Fn(&DVector<f64>) -> DMatrix<f64> + 'a>;
+
+
+/// Function to minimize, every evaluation is counted.
+pub struct Objective<'a> {
+    f: Function<'a>,
+    gradient: Option<Gradient<'a>>,
+    hessian: Option<Hessian<'a>>,
+    f_evals: Cell<u64>,
+    g_evals: Cell<u64>,
+    h_evals: Cell<u64>,
+}
+
+impl<'a> Objective<'a> {
+    /// Step of central differences is `DERIVATIVE_DELTA * max(1, max_i |x_i|)` along unit direction,
+    /// `≈ ε^(1/3)` is optimal for them.
+    pub const DERIVATIVE_DELTA: f64 = 6e-6;
+    /// Same for second derivatives, `≈ ε^(1/4)` is optimal for them.
+    pub const HESSIAN_DELTA: f64 = 1e-4;
+
+    pub fn new(f: impl Fn(&DVector<f64>) -> f64 + 'a) -> Self {
+        Self {
+            f: Box::new(f),
+            gradient: None,
+            hessian: None,
+            f_evals: Cell::new(0),
+            g_evals: Cell::new(0),
+            h_evals: Cell::new(0),
+        }
+    }
+
+    /// Analytic gradient, which is used instead of numerical one.
+    pub fn with_gradient(self, gradient: impl Fn(&DVector<f64>) -> DVector<f64> + 'a) -> Self {
+        Self { gradient: Some(Box::new(gradient)), ..self }
+    }
+
+    /// Analytic Hessian, which is used instead of finite-difference one.
+    pub fn with_hessian(self, hessian: impl Fn(&DVector<f64>) -> DMatrix<f64> + 'a) -> Self {
+        Self { hessian: Some(Box::new(hessian)), ..self }
+    }
+
+    /// Number of evaluations of `f` so far, including ones by numerical derivatives.
+    pub fn f_evals(&self) -> u64 { self.f_evals.get() }
+    /// Number of evaluations of user-supplied gradient so far.
+    pub fn g_evals(&self) -> u64 { self.g_evals.get() }
+    /// Number of evaluations of user-supplied Hessian so far.
+    pub fn h_evals(&self) -> u64 { self.h_evals.get() }
+
+    pub fn value(&self, x: &DVector<f64>) -> f64 {
+        self.f_evals.set(self.f_evals.get() + 1);
+        (self.f)(x)
+    }
+
+    /// `∇f`, if it isn't user-supplied, then it's by central differences, it's `2n` evaluations of `f`.
+    pub fn gradient(&self, x: &DVector<f64>) -> DVector<f64> {
+        if let Some(gradient) = &self.gradient {
+            self.g_evals.set(self.g_evals.get() + 1);
+            return gradient(x);
+        }
+        DVector::from_fn(x.len(), |i, _| {
+            let mut direction = DVector::zeros(x.len());
+            direction[i] = 1.;
+            self.derivative_along(x, &direction)
+        })
+    }
+
+    /// Derivative along `direction`, it's `∇f · direction`, without user-supplied gradient it's by central differences,
+    /// it's `2` evaluations of `f`.
+    pub fn derivative_along(&self, x: &DVector<f64>, direction: &DVector<f64>) -> f64 {
+        if self.gradient.is_some() { return self.gradient(x).dot(direction); }
+        let delta = Self::DERIVATIVE_DELTA * x.amax().max(1.) / direction.norm();
+        (self.value(&(x + delta * direction)) - self.value(&(x - delta * direction))) / (2. * delta)
+    }
+
+    /// `∇²f`, if it isn't user-supplied, then it's by central differences of gradient (`2n` its evaluations),
+    /// or by second differences of `f` (`2n² + 1` evaluations), if gradient isn't user-supplied too.
+    pub fn hessian(&self, x: &DVector<f64>) -> DMatrix<f64> {
+        if let Some(hessian) = &self.hessian {
+            self.h_evals.set(self.h_evals.get() + 1);
+            return hessian(x);
+        }
+        let n = x.len();
+        let delta = |i: usize| Self::HESSIAN_DELTA * x[i].abs().max(1.);
+        let shifted = |shifts: &[(usize, f64)]| {
+            let mut x = x.clone();
+            for &(i, shift) in shifts { x[i] += shift; }
+            x
+        };
+        if self.gradient.is_some() {
+            let mut h = DMatrix::zeros(n, n);
+            for j in 0..n {
+                let column = (self.gradient(&shifted(&[(j, delta(j))])) - self.gradient(&shifted(&[(j, -delta(j))])))
+                    / (2. * delta(j));
+                h.set_column(j, &column);
+            }
+            return (&h + h.transpose()) / 2.;
+        }
+        let value = self.value(x);
+        let mut h = DMatrix::zeros(n, n);
+        for i in 0..n {
+            let di = delta(i);
+            h[(i, i)] = (self.value(&shifted(&[(i, di)])) - 2. * value + self.value(&shifted(&[(i, -di)]))) / (di * di);
+            for j in 0..i {
+                let dj = delta(j);
+                let h_ij = (
+                    self.value(&shifted(&[(i, di), (j, dj)])) - self.value(&shifted(&[(i, di), (j, -dj)]))
+                    - self.value(&shifted(&[(i, -di), (j, dj)])) + self.value(&shifted(&[(i, -di), (j, -dj)]))
+                ) / (4. * di * dj);
+                h[(i, j)] = h_ij;
+                h[(j, i)] = h_ij;
+            }
+        }
+        h
+    }
+}
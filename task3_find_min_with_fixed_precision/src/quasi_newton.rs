@@ -0,0 +1,92 @@
+//! Quasi-Newton methods: `d_k = -H_k ∇f_k`, where `H_k` approximates inverse Hessian by changes of gradient
+//! `y = ∇f_(k+1) - ∇f_k` along steps `s = x_(k+1) - x_k` (secant equation `H y = s`).
+
+use std::collections::VecDeque;
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::{descent::Directions, objective::Objective};
+
+
+/// Update is skipped, if curvature `s · y` isn't positive, so `H` stays positive definite.
+fn is_curvature_positive(s: &DVector<f64>, y: &DVector<f64>) -> bool {
+    s.dot(y) > f64::EPSILON * s.norm() * y.norm()
+}
+
+
+/// Broyden–Fletcher–Goldfarb–Shanno: `H_(k+1) = (I - ρ s yᵀ) H_k (I - ρ y sᵀ) + ρ s sᵀ`, where `ρ = 1 / (yᵀ s)`.
+/// Before first update `H_0 = (sᵀ y / yᵀ y) I`.
+#[derive(Default)]
+pub(crate) struct Bfgs {
+    inverse_hessian: Option<DMatrix<f64>>,
+}
+
+impl Directions for Bfgs {
+    fn direction(&mut self, _objective: &Objective, _x: &DVector<f64>, gradient: &DVector<f64>) -> (DVector<f64>, Option<f64>) {
+        match &self.inverse_hessian {
+            Some(h) => (-(h * gradient), Some(1.)),
+            None => (-gradient, None),
+        }
+    }
+
+    fn update(&mut self, s: &DVector<f64>, y: &DVector<f64>) {
+        if !is_curvature_positive(s, y) { return; }
+        let rho = 1. / y.dot(s);
+        let h = self.inverse_hessian.take()
+            .unwrap_or_else(|| DMatrix::identity(s.len(), s.len()) * (s.dot(y) / y.norm_squared()));
+        let identity = DMatrix::<f64>::identity(s.len(), s.len());
+        let left = &identity - rho * s * y.transpose();
+        let right = &identity - rho * y * s.transpose();
+        self.inverse_hessian = Some(left * h * right + rho * s * s.transpose());
+    }
+
+    fn reset(&mut self) {
+        self.inverse_hessian = None;
+    }
+}
+
+
+/// Limited-memory BFGS: `H_k ∇f_k` is computed by two-loop recursion from last `memory` pairs `(s, y)`,
+/// and `H_0 = (sᵀ y / yᵀ y) I` by last pair, so it's `O(memory n)` memory and time.
+pub(crate) struct Lbfgs {
+    memory: usize,
+    /// `(s, y, ρ)`, from oldest to newest.
+    pairs: VecDeque<(DVector<f64>, DVector<f64>, f64)>,
+}
+
+impl Lbfgs {
+    pub(crate) fn new(memory: usize) -> Self {
+        Self { memory, pairs: VecDeque::with_capacity(memory) }
+    }
+}
+
+impl Directions for Lbfgs {
+    fn direction(&mut self, _objective: &Objective, _x: &DVector<f64>, gradient: &DVector<f64>) -> (DVector<f64>, Option<f64>) {
+        let Some((s_last, y_last, _)) = self.pairs.back() else { return (-gradient, None) };
+        let gamma = s_last.dot(y_last) / y_last.norm_squared();
+        let mut q = gradient.clone();
+        let alphas: Vec<f64> = self.pairs.iter().rev()
+            .map(|(s, y, rho)| {
+                let alpha = rho * s.dot(&q);
+                q -= alpha * y;
+                alpha
+            })
+            .collect();
+        let mut r = gamma * q;
+        for ((s, y, rho), alpha) in self.pairs.iter().zip(alphas.into_iter().rev()) {
+            let beta = rho * y.dot(&r);
+            r += (alpha - beta) * s;
+        }
+        (-r, Some(1.))
+    }
+
+    fn update(&mut self, s: &DVector<f64>, y: &DVector<f64>) {
+        if self.memory == 0 || !is_curvature_positive(s, y) { return; }
+        if self.pairs.len() == self.memory { self.pairs.pop_front(); }
+        self.pairs.push_back((s.clone(), y.clone(), 1. / y.dot(s)));
+    }
+
+    fn reset(&mut self) {
+        self.pairs.clear();
+    }
+}
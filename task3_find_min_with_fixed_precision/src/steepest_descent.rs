@@ -0,0 +1,14 @@
+//! Steepest descent: moves along antigradient.
+
+use nalgebra::DVector;
+
+use crate::{descent::Directions, objective::Objective};
+
+
+pub(crate) struct SteepestDescent;
+
+impl Directions for SteepestDescent {
+    fn direction(&mut self, _objective: &Objective, _x: &DVector<f64>, gradient: &DVector<f64>) -> (DVector<f64>, Option<f64>) {
+        (-gradient, None)
+    }
+}
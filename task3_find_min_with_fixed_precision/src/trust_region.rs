@@ -0,0 +1,133 @@
+//! Trust-region method: step `p` minimizes quadratic model `m(p) = f + ∇f · p + ½ pᵀ ∇²f p` in ball `|p| <= Δ`,
+//! and `Δ` is adjusted by ratio of actual and predicted reductions. See Nocedal, Wright, chapters 4 and 7.
+
+use nalgebra::{DMatrix, DVector};
+
+use crate::{
+    minimizer::{Minimizer, Minimum, StopReason},
+    modified_cholesky::modified_cholesky,
+    objective::Objective,
+};
+
+
+/// Approximate solver of trust-region subproblem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustRegionSubproblem {
+    /// Path from Cauchy point to Newton's point, for model with positive definite Hessian
+    /// made by [modified Cholesky](crate::modified_cholesky).
+    Dogleg,
+    /// Conjugate gradient on model, which stops on boundary of region or on negative curvature, so Hessian
+    /// can be indefinite, and it isn't factorized.
+    SteihaugCg,
+}
+
+
+impl Minimizer {
+    pub const TRUST_RADIUS_INITIAL: f64 = 1.;
+    pub const TRUST_RADIUS_MAX: f64 = 1e3;
+    /// Step is accepted, if actual reduction is at least this part of predicted one.
+    pub const TRUST_ACCEPT_RATIO: f64 = 0.1;
+
+    /// Rejected step is iteration too. It stops by step, if `Δ <= x_tolerance`, or by
+    /// [`LineSearchFailed`](StopReason::LineSearchFailed), if last step is rejected, because `f` or model isn't finite.
+    pub(crate) fn minimize_by_trust_region(
+        &self,
+        objective: &Objective,
+        x_start: DVector<f64>,
+        subproblem: TrustRegionSubproblem,
+    ) -> Minimum {
+        let mut x = x_start;
+        let mut value = objective.value(&x);
+        let mut gradient = objective.gradient(&x);
+        let mut hessian = objective.hessian(&x);
+        let mut radius = Self::TRUST_RADIUS_INITIAL;
+        let mut iter = 0;
+        loop {
+            if gradient.norm() <= self.gradient_tolerance {
+                return Minimum::new(x, value, iter, StopReason::Gradient);
+            }
+            iter += 1;
+            let (p, model_hessian) = match subproblem {
+                TrustRegionSubproblem::Dogleg => dogleg(&gradient, &hessian, radius),
+                TrustRegionSubproblem::SteihaugCg => (steihaug_cg(&gradient, &hessian, radius), hessian.clone()),
+            };
+            let predicted_reduction = -(gradient.dot(&p) + p.dot(&(&model_hessian * &p)) / 2.);
+            let x_new = &x + &p;
+            let value_new = objective.value(&x_new);
+            let ratio = (value - value_new) / predicted_reduction;
+            let p_norm = p.norm();
+            if ratio.is_nan() || ratio < 0.25 {
+                // `p` is NaN, if gradient or Hessian is, and `min` ignores NaN:
+                radius = p_norm.min(radius) / 4.;
+            } else if ratio > 0.75 && p_norm >= 0.99 * radius {
+                radius = (2. * radius).min(Self::TRUST_RADIUS_MAX);
+            }
+            let is_accepted = ratio > Self::TRUST_ACCEPT_RATIO && value_new.is_finite();
+            let (step, value_change) = if is_accepted { (p_norm, value_new - value) } else { (radius, f64::INFINITY) };
+            if is_accepted {
+                (x, value) = (x_new, value_new);
+            } else if (ratio.is_nan() || !value_new.is_finite()) && radius <= self.x_tolerance {
+                return Minimum::new(x, value, iter, StopReason::LineSearchFailed);
+            }
+            if let Some(stop_reason) = self.stop_reason(objective, iter, step, value_change) {
+                return Minimum::new(x, value, iter, stop_reason);
+            }
+            if is_accepted {
+                gradient = objective.gradient(&x);
+                hessian = objective.hessian(&x);
+            }
+        }
+    }
+}
+
+
+/// Min of model along path `0 → p_U → p_B`, where `p_U = -(gᵀg / gᵀBg) g` is min along antigradient,
+/// and `p_B = -B⁻¹ g` is Newton's step, for `B = ∇²f + τ I`. Returns `(p, B)`.
+fn dogleg(gradient: &DVector<f64>, hessian: &DMatrix<f64>, radius: f64) -> (DVector<f64>, DMatrix<f64>) {
+    let Some((cholesky, tau)) = modified_cholesky(hessian) else {
+        return (-radius / gradient.norm() * gradient, hessian.clone());
+    };
+    let b = hessian + DMatrix::identity(hessian.nrows(), hessian.ncols()) * tau;
+    let p_b = -cholesky.solve(gradient);
+    if p_b.norm() <= radius { return (p_b, b); }
+    let p_u = -(gradient.norm_squared() / gradient.dot(&(&b * gradient))) * gradient;
+    if p_u.norm() >= radius { return (radius / p_u.norm() * p_u, b); }
+    let t = step_to_boundary(&p_u, &(&p_b - &p_u), radius);
+    (&p_u + t * (&p_b - &p_u), b)
+}
+
+
+/// Nocedal, Wright, algorithm 7.2: CG iterations for `∇²f p = -∇f` from `p = 0`, until residual is
+/// `<= min(0.5, √|∇f|) |∇f|`, or `p` leaves region, or direction of negative curvature is found.
+fn steihaug_cg(gradient: &DVector<f64>, hessian: &DMatrix<f64>, radius: f64) -> DVector<f64> {
+    let tolerance = gradient.norm().sqrt().min(0.5) * gradient.norm();
+    let mut z = DVector::zeros(gradient.len());
+    let mut r = gradient.clone();
+    let mut d = -gradient;
+    for _ in 0..2 * gradient.len() {
+        let hd = hessian * &d;
+        let curvature = d.dot(&hd);
+        if curvature <= 0. {
+            return &z + step_to_boundary(&z, &d, radius) * &d;
+        }
+        let alpha = r.norm_squared() / curvature;
+        let z_new = &z + alpha * &d;
+        if z_new.norm() >= radius {
+            return &z + step_to_boundary(&z, &d, radius) * &d;
+        }
+        let r_new = &r + alpha * hd;
+        if r_new.norm() <= tolerance { return z_new; }
+        let beta = r_new.norm_squared() / r.norm_squared();
+        d = -&r_new + beta * d;
+        (z, r) = (z_new, r_new);
+    }
+    z
+}
+
+
+/// `t >= 0`, where `|z + t d| = radius`, for `|z| <= radius`.
+fn step_to_boundary(z: &DVector<f64>, d: &DVector<f64>, radius: f64) -> f64 {
+    // `|d|² t² + 2 (z · d) t + |z|² - radius² = 0`:
+    let (a, b, c) = (d.norm_squared(), z.dot(d), z.norm_squared() - radius * radius);
+    (-b + (b * b - a * c).max(0.).sqrt()) / a
+}